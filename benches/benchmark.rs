@@ -1,5 +1,7 @@
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use num::{BigRational, FromPrimitive, ToPrimitive};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use hyper_catalan_rs::{evaluate_fixed, evaluate_polynomial, HyperCatalanPolynomialSolver};
+use hyper_catalan_rs::subdigon::{generate_types, SubdigonTypes};
+use num::{BigInt, BigRational, FromPrimitive};
 use std::collections::HashMap;
 
 // Simple implementation of SubdigonType for benchmarking
@@ -13,10 +15,12 @@ impl SubdigonType {
         SubdigonType { m }
     }
 
+    #[allow(dead_code)]
     fn faces(&self) -> usize {
         self.m.iter().sum()
     }
 
+    #[allow(dead_code)]
     fn edges(&self) -> usize {
         let total = self.m.iter().enumerate()
             .map(|(i, &count)| (i + 2) * count)
@@ -24,6 +28,7 @@ impl SubdigonType {
         total / 2
     }
 
+    #[allow(dead_code)]
     fn vertices(&self) -> usize {
         self.edges() - self.faces() + 2
     }
@@ -42,7 +47,7 @@ impl HyperCatalanCalculator {
     fn factorial(&self, n: usize) -> BigRational {
         let mut result = BigRational::from_u64(1).unwrap();
         for i in 2..=n {
-            result = result * BigRational::from_u64(i as u64).unwrap();
+            result *= BigRational::from_u64(i as u64).unwrap();
         }
         result
     }
@@ -71,7 +76,7 @@ impl HyperCatalanCalculator {
         let mut denominator = self.factorial(v);
 
         for &count in &subdigon_type.m {
-            denominator = denominator * self.factorial(count);
+            denominator *= self.factorial(count);
         }
 
         let result = numerator / denominator;
@@ -185,11 +190,124 @@ fn higher_degree_equation_benchmark(c: &mut Criterion) {
     });
 }
 
+fn series_degree_five_benchmark(c: &mut Criterion) {
+    // Hyper-Catalan series summation for x^5 + 0.1x^3 - 0.2x^2 + 5x + 1 = 0;
+    // dominated by the t_i^k products that are precomputed per face level
+    let coefficients = vec![1.0, 5.0, -0.2, 0.1, 0.0, 1.0];
+    let mut solver = HyperCatalanPolynomialSolver::new(5, 12);
+
+    c.bench_function("series_degree_five", |b| {
+        b.iter(|| {
+            let root = solver.solve_polynomial(black_box(&coefficients));
+            black_box(root).ok();
+        })
+    });
+}
+
+fn series_solve_benchmark(c: &mut Criterion) {
+    // The real Hyper-Catalan series path at increasing face levels, on a quadratic
+    // (6 + 5x + x^2, roots -2 and -3) and a cubic (1 + 5x - 0.2x^2 + 0.1x^3) that
+    // are both inside the series' radius of convergence
+    let polynomials = [
+        ("quadratic", vec![6.0, 5.0, 1.0]),
+        ("cubic", vec![1.0, 5.0, -0.2, 0.1]),
+    ];
+
+    for (name, coefficients) in &polynomials {
+        for max_terms in [10, 20, 30] {
+            let mut solver = HyperCatalanPolynomialSolver::for_coefficients(coefficients, max_terms);
+            c.bench_function(&format!("series_solve_{}_{}", name, max_terms), |b| {
+                b.iter(|| {
+                    let root = solver.solve_polynomial_with_progress(black_box(coefficients), |_, _| {});
+                    black_box(root).ok();
+                })
+            });
+        }
+    }
+}
+
+fn exact_vs_float_series_benchmark(c: &mut Criterion) {
+    // Price of exactness: the series summed in BigRational by solve_polynomial_exact
+    // versus converting each term to f64, for 1 + 5x + (x^2 + ... + x^n)/10 with
+    // n = 2..6. The bit size of the exact sum is printed once per degree, since it is
+    // what makes the rational path slow.
+    let mut group = c.benchmark_group("exact_vs_float_series");
+    for degree in 2..=6 {
+        let tenth = BigRational::new(BigInt::from(1), BigInt::from(10));
+        let mut exact = vec![BigRational::from_integer(BigInt::from(1)), BigRational::from_integer(BigInt::from(5))];
+        exact.extend(std::iter::repeat_n(tenth, degree - 1));
+        let mut float = vec![1.0, 5.0];
+        float.extend(std::iter::repeat_n(0.1, degree - 1));
+
+        let mut solver = HyperCatalanPolynomialSolver::new(degree, 8);
+        solver.set_residual_tolerance(None);
+        if let Some(sum) = solver.solve_polynomial_exact(&exact).ok().and_then(|report| report.series_value_exact) {
+            println!(
+                "degree {}: exact series sum has a {}-bit numerator and a {}-bit denominator",
+                degree,
+                sum.numer().bits(),
+                sum.denom().bits()
+            );
+        }
+
+        group.bench_with_input(BenchmarkId::new("rational", degree), &exact, |b, coefficients| {
+            b.iter(|| black_box(solver.solve_polynomial_exact(black_box(coefficients))).ok())
+        });
+        group.bench_with_input(BenchmarkId::new("float", degree), &float, |b, coefficients| {
+            b.iter(|| black_box(solver.solve_polynomial_with_progress(black_box(coefficients), |_, _| {})).ok())
+        });
+    }
+    group.finish();
+}
+
+fn fixed_degree_evaluate_benchmark(c: &mut Criterion) {
+    // Evaluating the cubic x^3 - 6x^2 + 11x - 6 with the const-generic Horner loop
+    // versus the slice version, at a spread of points so neither is constant-folded
+    let coefficients = [-6.0, 11.0, -6.0, 1.0];
+    let points: Vec<f64> = (0..1000).map(|i| i as f64 / 250.0).collect();
+
+    let mut group = c.benchmark_group("evaluate_cubic");
+    group.throughput(Throughput::Elements(points.len() as u64));
+    group.bench_function("fixed", |b| {
+        b.iter(|| points.iter().map(|&x| evaluate_fixed(black_box(&coefficients), x)).sum::<f64>())
+    });
+    group.bench_function("slice", |b| {
+        b.iter(|| points.iter().map(|&x| evaluate_polynomial(black_box(&coefficients), x)).sum::<f64>())
+    });
+    group.finish();
+}
+
+fn type_generation_benchmark(c: &mut Criterion) {
+    // Scaling of subdigon type enumeration; the throughput is the number of types
+    // produced, so criterion reports types per second alongside the time
+    let mut group = c.benchmark_group("type_generation");
+    for max_polygon_size in [3, 5] {
+        for total_faces in [4, 8, 12] {
+            let count = generate_types(total_faces, max_polygon_size).len();
+            group.throughput(Throughput::Elements(count as u64));
+            let parameter = format!("{}x{}", total_faces, max_polygon_size);
+
+            group.bench_with_input(BenchmarkId::new("generate_types", &parameter), &total_faces, |b, &faces| {
+                b.iter(|| black_box(generate_types(black_box(faces), max_polygon_size)))
+            });
+            group.bench_with_input(BenchmarkId::new("subdigon_types", &parameter), &total_faces, |b, &faces| {
+                b.iter(|| black_box(SubdigonTypes::new(black_box(faces), max_polygon_size).count()))
+            });
+        }
+    }
+    group.finish();
+}
+
 criterion_group!(
     benches,
     hyper_catalan_number_benchmark,
     quadratic_equation_benchmark,
     cubic_equation_benchmark,
-    higher_degree_equation_benchmark
+    higher_degree_equation_benchmark,
+    series_degree_five_benchmark,
+    series_solve_benchmark,
+    exact_vs_float_series_benchmark,
+    fixed_degree_evaluate_benchmark,
+    type_generation_benchmark
 );
 criterion_main!(benches); 
\ No newline at end of file
@@ -0,0 +1,112 @@
+//! Convergence acceleration for sequences of series partial sums
+
+use crate::solver::HighPrecFloat;
+
+/// Estimate the limit of a sequence by Richardson extrapolation
+///
+/// Assumes the error model `S_n = S + a₁/n + a₂/n² + ...`, where `n` is the
+/// 1-based position of the partial sum in the sequence: the last `orders + 1`
+/// partial sums are fitted by a polynomial in `h = 1/n` of degree `orders`, which
+/// is evaluated at `h = 0` with Neville's algorithm. Uses as many points as are
+/// available when the sequence is shorter; an empty sequence gives NaN.
+pub fn richardson_extrapolate(partial_sums: &[HighPrecFloat], orders: usize) -> HighPrecFloat {
+    let count = (orders + 1).min(partial_sums.len());
+    if count == 0 {
+        return HighPrecFloat::NAN;
+    }
+
+    let start = partial_sums.len() - count;
+    let h: Vec<HighPrecFloat> = (start..partial_sums.len())
+        .map(|i| 1.0 / (i + 1) as HighPrecFloat)
+        .collect();
+    let mut table = partial_sums[start..].to_vec();
+
+    // After level j, table[i] extrapolates the points i..=i+j to h = 0
+    for j in 1..count {
+        for i in 0..count - j {
+            table[i] = (h[i] * table[i + 1] - h[i + j] * table[i]) / (h[i] - h[i + j]);
+        }
+    }
+    table[0]
+}
+
+/// Estimate the limit of a sequence with Aitken's Δ² process on its last three terms
+///
+/// Exact for sequences whose error shrinks geometrically, `S_n = S + c·qⁿ`. Falls
+/// back to the last term when the second difference vanishes or fewer than three
+/// terms are given.
+pub fn aitken_extrapolate(partial_sums: &[HighPrecFloat]) -> HighPrecFloat {
+    let n = partial_sums.len();
+    if n < 3 {
+        return partial_sums.last().copied().unwrap_or(HighPrecFloat::NAN);
+    }
+    let (s0, s1, s2) = (partial_sums[n - 3], partial_sums[n - 2], partial_sums[n - 1]);
+    let second_difference = s2 - 2.0 * s1 + s0;
+    if second_difference == 0.0 {
+        return s2;
+    }
+    s2 - (s2 - s1) * (s2 - s1) / second_difference
+}
+
+/// Estimate the limit of a sequence with the order-`k` Shanks transformation
+///
+/// Computed with Wynn's ε-algorithm on the last `2k + 1` terms (or as many as are
+/// available, rounded down to an odd count), which is exact when the error is a sum
+/// of `k` geometric terms. `Shanks(1)` coincides with Aitken's Δ². If a difference
+/// in the ε table vanishes, the latest even column has already converged and its
+/// newest entry is returned.
+pub fn shanks_extrapolate(partial_sums: &[HighPrecFloat], k: usize) -> HighPrecFloat {
+    let available = partial_sums.len();
+    if available == 0 {
+        return HighPrecFloat::NAN;
+    }
+    let order = k.min((available - 1) / 2);
+    let count = 2 * order + 1;
+
+    // Columns ε_{-1} = 0 and ε_0 = S; ε_{j+1}[i] = ε_{j-1}[i+1] + 1/(ε_j[i+1] - ε_j[i])
+    let mut previous = vec![0.0; count + 1];
+    let mut current = partial_sums[available - count..].to_vec();
+    let mut estimate = partial_sums[available - 1];
+    for column in 0..2 * order {
+        if column % 2 == 0 {
+            estimate = current[current.len() - 1];
+        }
+        let mut next = Vec::with_capacity(current.len() - 1);
+        for i in 0..current.len() - 1 {
+            let difference = current[i + 1] - current[i];
+            if difference == 0.0 {
+                return estimate;
+            }
+            next.push(previous[i + 1] + 1.0 / difference);
+        }
+        previous = current;
+        current = next;
+    }
+    current[0]
+}
+
+/// Strategy for accelerating the series partial sums before mapping to a root
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Acceleration {
+    /// Use the last partial sum as is
+    #[default]
+    None,
+    /// Aitken's Δ² process, see `aitken_extrapolate`
+    Aitken,
+    /// Shanks transformation of the given order, see `shanks_extrapolate`
+    Shanks(usize),
+    /// Richardson extrapolation with the given number of orders, see `richardson_extrapolate`
+    Richardson(usize),
+}
+
+impl Acceleration {
+    /// Estimate the limit of the partial sums with this strategy
+    pub fn apply(&self, partial_sums: &[HighPrecFloat]) -> HighPrecFloat {
+        match *self {
+            Acceleration::None => partial_sums.last().copied().unwrap_or(HighPrecFloat::NAN),
+            Acceleration::Aitken => aitken_extrapolate(partial_sums),
+            Acceleration::Shanks(k) => shanks_extrapolate(partial_sums, k),
+            Acceleration::Richardson(orders) => richardson_extrapolate(partial_sums, orders),
+        }
+    }
+}
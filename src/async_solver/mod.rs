@@ -0,0 +1,28 @@
+use std::sync::{Arc, Mutex};
+
+use crate::error::SolverError;
+use crate::solver::{HighPrecFloat, HyperCatalanPolynomialSolver};
+
+/// Solve a polynomial on tokio's blocking thread pool
+///
+/// Series summation is pure CPU work with no points where it could yield, so making
+/// the algorithm itself `async` would only stall the runtime's worker threads. Instead
+/// the whole solve runs via `spawn_blocking`, leaving the async executor free while it
+/// completes. The solver is shared behind a mutex so its Hyper-Catalan cache persists
+/// across calls; a poisoned lock is recovered since the cache is always left valid.
+///
+/// Panics from the solve are propagated to the awaiting task.
+pub async fn solve_polynomial_async(
+    solver: Arc<Mutex<HyperCatalanPolynomialSolver>>,
+    coefficients: Vec<HighPrecFloat>,
+) -> Result<HighPrecFloat, SolverError> {
+    let task = tokio::task::spawn_blocking(move || {
+        let mut solver = solver.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        solver.solve_polynomial(&coefficients)
+    });
+
+    match task.await {
+        Ok(result) => result,
+        Err(error) => std::panic::resume_unwind(error.into_panic()),
+    }
+}
@@ -0,0 +1,93 @@
+use std::ops::Mul;
+
+use crate::solver::HighPrecFloat;
+
+/// Polynomial in two variables, `Σ coefficients[i][j] · xⁱ yʲ`
+///
+/// Rows may have different lengths; missing entries are zero. The `coefficients`
+/// field is the `Vec<Vec<f64>>` layout taken by `solve_two_univariate_via_resultant`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BivariatePolynomial {
+    pub coefficients: Vec<Vec<HighPrecFloat>>,
+}
+
+impl BivariatePolynomial {
+    /// Create a bivariate polynomial from coefficients indexed by `(i, j)` powers of `(x, y)`
+    pub fn new(coefficients: Vec<Vec<HighPrecFloat>>) -> Self {
+        BivariatePolynomial { coefficients }
+    }
+
+    /// Coefficient of `xⁱ yʲ`, zero when absent
+    pub fn coefficient(&self, i: usize, j: usize) -> HighPrecFloat {
+        self.coefficients
+            .get(i)
+            .and_then(|row| row.get(j))
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    /// Highest power of x with a nonzero coefficient (zero for the zero polynomial)
+    pub fn degree_x(&self) -> usize {
+        self.coefficients
+            .iter()
+            .rposition(|row| row.iter().any(|&c| c != 0.0))
+            .unwrap_or(0)
+    }
+
+    /// Highest power of y with a nonzero coefficient (zero for the zero polynomial)
+    pub fn degree_y(&self) -> usize {
+        self.coefficients
+            .iter()
+            .filter_map(|row| row.iter().rposition(|&c| c != 0.0))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Coefficients in y of `p(x, y)` for a fixed x, constant term first, up to `degree_y`
+    pub fn specialize_x(&self, x: HighPrecFloat) -> Vec<HighPrecFloat> {
+        let degree_y = self.degree_y();
+        let mut result = vec![0.0; degree_y + 1];
+        for row in self.coefficients.iter().rev() {
+            for (j, value) in result.iter_mut().enumerate() {
+                *value = *value * x + row.get(j).copied().unwrap_or(0.0);
+            }
+        }
+        result
+    }
+
+    /// Evaluate at `(x, y)` with Horner's scheme in both variables
+    pub fn eval(&self, x: HighPrecFloat, y: HighPrecFloat) -> HighPrecFloat {
+        self.coefficients.iter().rev().fold(0.0, |acc, row| {
+            let row_value = row.iter().rev().fold(0.0, |inner, &c| inner * y + c);
+            acc * x + row_value
+        })
+    }
+}
+
+impl Mul for &BivariatePolynomial {
+    type Output = BivariatePolynomial;
+
+    fn mul(self, other: &BivariatePolynomial) -> BivariatePolynomial {
+        let (ax, ay) = (self.degree_x(), self.degree_y());
+        let (bx, by) = (other.degree_x(), other.degree_y());
+        let mut product = vec![vec![0.0; ay + by + 1]; ax + bx + 1];
+        for (i, row) in self.coefficients.iter().enumerate() {
+            for (j, &a) in row.iter().enumerate().filter(|(_, &a)| a != 0.0) {
+                for (k, other_row) in other.coefficients.iter().enumerate() {
+                    for (l, &b) in other_row.iter().enumerate() {
+                        product[i + k][j + l] += a * b;
+                    }
+                }
+            }
+        }
+        BivariatePolynomial::new(product)
+    }
+}
+
+impl Mul for BivariatePolynomial {
+    type Output = BivariatePolynomial;
+
+    fn mul(self, other: BivariatePolynomial) -> BivariatePolynomial {
+        &self * &other
+    }
+}
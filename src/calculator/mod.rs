@@ -1,14 +1,61 @@
 use std::collections::HashMap;
 use num::BigRational;
-use num_bigint::BigInt;
-use num_traits::One;
+use num::BigInt;
+use num::{One, Zero};
 
 use crate::subdigon::SubdigonType;
 
+/// Calculate the factorial as a BigInt
+fn factorial(n: i64) -> BigInt {
+    if n <= 1 {
+        return BigInt::one();
+    }
+
+    let mut result = BigInt::one();
+    for i in 2..=n {
+        result *= i;
+    }
+    result
+}
+
+/// Calculate the Hyper-Catalan number for a given subdigon type, without caching
+///
+/// A pure function of the type, usable from iterators and parallel code without a
+/// `HyperCatalanCalculator`; the calculator's `calculate` returns the same values.
+pub fn hyper_catalan_number(type_: &SubdigonType) -> BigRational {
+    // Numerator: (2*m₂ + 3*m₃ + 4*m₄ + ...)!, the edge count of the subdivided
+    // polygon less one. Counted in i64, since these sums overflow i32 long before
+    // the counts themselves do.
+    let mut e: i64 = 0;
+    for (i, &count) in type_.m.iter().enumerate() {
+        e += (i as i64 + 2) * count as i64;
+    }
+
+    // Denominator: (1 + m₂ + 2*m₃ + 3*m₄ + ...)!, the vertex count less one
+    let mut v: i64 = 1;
+    for (i, &count) in type_.m.iter().enumerate() {
+        v += (i as i64 + 1) * count as i64;
+    }
+
+    // Calculate the Hyper-Catalan number using the formula from Theorem 5
+    let numerator = factorial(e);
+    let mut denominator = factorial(v);
+
+    // Multiply by factorial of each m_i
+    for &count in &type_.m {
+        if count > 0 {
+            denominator *= factorial(count as i64);
+        }
+    }
+
+    BigRational::new(numerator, denominator)
+}
+
 /// Calculator for Hyper-Catalan numbers
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct HyperCatalanCalculator {
     cache: HashMap<SubdigonType, BigRational>,
+    motzkin_cache: Vec<BigInt>,
 }
 
 impl HyperCatalanCalculator {
@@ -16,68 +63,150 @@ impl HyperCatalanCalculator {
     pub fn new() -> Self {
         HyperCatalanCalculator {
             cache: HashMap::new(),
+            motzkin_cache: Vec::new(),
         }
     }
 
-    /// Calculate the factorial as a BigInt
-    fn factorial(&self, n: i32) -> BigInt {
-        if n <= 1 {
-            return BigInt::one();
-        }
-        
-        let mut result = BigInt::one();
-        for i in 2..=n {
-            result *= i;
-        }
-        result
-    }
-
     /// Calculate the Hyper-Catalan number for a given subdigon type
+    ///
+    /// Values are cached, so repeated types cost a hash lookup; see
+    /// `hyper_catalan_number` for the uncached formula.
     pub fn calculate(&mut self, type_: &SubdigonType) -> BigRational {
         // Check the cache first
         if let Some(cached) = self.cache.get(type_) {
             return cached.clone();
         }
 
-        // Calculate the number of edges: 2*m₂ + 3*m₃ + 4*m₄ + ... divided by 2
-        let mut e = 0;
-        for (i, &count) in type_.m.iter().enumerate() {
-            e += (i as i32 + 2) * count;
-        }
-        // Divide by 2 to account for each edge being counted twice
-        e /= 2;
+        let result = hyper_catalan_number(type_);
 
-        // Calculate the number of vertices: 1 + m₂ + 2*m₃ + 3*m₄ + ...
-        let mut v = 1;
-        for (i, &count) in type_.m.iter().enumerate() {
-            v += (i as i32) * count;
-        }
+        // Store in cache
+        self.cache.insert(type_.clone(), result.clone());
+
+        result
+    }
 
-        // Calculate the Hyper-Catalan number using the formula from Theorem 5
-        let numerator = self.factorial(e);
-        let mut denominator = self.factorial(v);
+    /// The Hyper-Catalan number as an integer, or `None` if it does not reduce to one
+    ///
+    /// `calculate` returns a reduced `BigRational`, and Theorem 5 always yields an
+    /// integer, so this is `Some` for every valid type.
+    pub fn hyper_catalan_integer(&mut self, type_: &SubdigonType) -> Option<BigInt> {
+        let value = self.calculate(type_);
+        value.is_integer().then(|| value.to_integer())
+    }
 
-        // Multiply by factorial of each m_i
-        for &count in &type_.m {
-            if count > 0 {
-                denominator *= self.factorial(count);
-            }
+    /// Calculate the Hyper-Catalan number from the series' functional equation
+    ///
+    /// The generating series `S = Σ C_m · t₂^m₂ · t₃^m₃ · ...` solves
+    /// `S = 1 + t₂S² + t₃S³ + ...`, so `C_m` is the sum over each `i` with `mᵢ > 0` of
+    /// the coefficient of `t^(m - eᵢ)` in `S^i`. Those coefficients are expanded
+    /// recursively from smaller types, without the factorial formula or the cache used
+    /// by `calculate`, which makes this an independent check on it.
+    pub fn calculate_recursive(&self, type_: &SubdigonType) -> BigInt {
+        let mut numbers = HashMap::new();
+        let mut powers = HashMap::new();
+        recursive_number(&type_.m, &mut numbers, &mut powers)
+    }
+
+    /// Calculate the n-th Motzkin number: 1, 1, 2, 4, 9, 21, 51, ...
+    ///
+    /// Motzkin numbers count the ways to draw non-crossing chords between n points on
+    /// a circle, and satisfy `M_n = Σ_k binom(n, 2k) · Cat_k`, where the Catalan numbers
+    /// `Cat_k` are the Hyper-Catalan numbers of the digon-only types `(k, 0, 0, ...)`.
+    /// Uses the recurrence `(n + 2)·M_n = (2n + 1)·M_{n-1} + 3(n - 1)·M_{n-2}`, with
+    /// previously computed values kept in a cache.
+    pub fn motzkin(&mut self, n: usize) -> BigInt {
+        while self.motzkin_cache.len() <= n {
+            let k = self.motzkin_cache.len();
+            let value = if k < 2 {
+                BigInt::one()
+            } else {
+                let previous = &self.motzkin_cache[k - 1] * (2 * k + 1);
+                let before = &self.motzkin_cache[k - 2] * (3 * (k - 1));
+                (previous + before) / (k + 2)
+            };
+            self.motzkin_cache.push(value);
         }
+        self.motzkin_cache[n].clone()
+    }
 
-        // Create the rational number result
-        let result = BigRational::new(numerator, denominator);
+    /// Number of Hyper-Catalan numbers currently cached
+    pub fn cache_size(&self) -> usize {
+        self.cache.len()
+    }
 
-        // Store in cache
-        self.cache.insert(type_.clone(), result.clone());
+    /// Drop every cached Hyper-Catalan and Motzkin number
+    pub fn clear_cache(&mut self) {
+        self.cache.clear();
+        self.motzkin_cache.clear();
+    }
 
-        result
+    /// Iterate over the cached `(type, Hyper-Catalan number)` pairs, in no particular order
+    pub fn cached_entries(&self) -> impl Iterator<Item = (&SubdigonType, &BigRational)> {
+        self.cache.iter()
     }
 
-    /// Print the contents of the cache (for debugging)
+    /// Print the contents of the cache (for debugging), ordered by subdigon type
     pub fn print_cache(&self) {
-        println!("Cache contains {} entries:", self.cache.len());
-        for (k, v) in &self.cache {
-            println!("C_{} = {}", k.to_string(), v);
+        println!("Cache contains {} entries:", self.cache_size());
+        let mut entries: Vec<_> = self.cached_entries().collect();
+        entries.sort_by(|a, b| a.0.cmp(b.0));
+        for (k, v) in entries {
+            println!("C_{} = {}", k, v);
+        }
+    }
+} 
+/// `C_m` from `S = 1 + Σ tᵢSⁱ`, memoized in `numbers`
+fn recursive_number(
+    m: &[i32],
+    numbers: &mut HashMap<Vec<i32>, BigInt>,
+    powers: &mut HashMap<(usize, Vec<i32>), BigInt>,
+) -> BigInt {
+    if let Some(value) = numbers.get(m) {
+        return value.clone();
+    }
+
+    let mut value = if m.iter().all(|&count| count == 0) { BigInt::one() } else { BigInt::zero() };
+    for i in (0..m.len()).filter(|&i| m[i] > 0) {
+        let mut rest = m.to_vec();
+        rest[i] -= 1;
+        value += power_coefficient(i + 2, &rest, numbers, powers);
+    }
+
+    numbers.insert(m.to_vec(), value.clone());
+    value
+}
+
+/// Coefficient of `t^m` in `S^k`, expanded as `Σ_{m' ≤ m} C_{m'} · [t^(m - m')] S^(k-1)`
+fn power_coefficient(
+    k: usize,
+    m: &[i32],
+    numbers: &mut HashMap<Vec<i32>, BigInt>,
+    powers: &mut HashMap<(usize, Vec<i32>), BigInt>,
+) -> BigInt {
+    if k == 0 {
+        return if m.iter().all(|&count| count == 0) { BigInt::one() } else { BigInt::zero() };
+    }
+    let key = (k, m.to_vec());
+    if let Some(value) = powers.get(&key) {
+        return value.clone();
+    }
+
+    // Walk every m' with 0 ≤ m'ᵢ ≤ mᵢ like an odometer
+    let mut value = BigInt::zero();
+    let mut part = vec![0; m.len()];
+    loop {
+        let remainder: Vec<i32> = m.iter().zip(&part).map(|(&a, &b)| a - b).collect();
+        value += recursive_number(&part, numbers, powers) * power_coefficient(k - 1, &remainder, numbers, powers);
+
+        match (0..m.len()).find(|&i| part[i] < m[i]) {
+            Some(i) => {
+                part[i] += 1;
+                part[..i].iter_mut().for_each(|count| *count = 0);
+            }
+            None => break,
         }
     }
-} 
\ No newline at end of file
+
+    powers.insert(key, value.clone());
+    value
+}
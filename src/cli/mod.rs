@@ -0,0 +1,166 @@
+//! Non-interactive modes of the command line binary, kept generic over the
+//! reader and writer so they can be driven from tests
+
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+
+use crate::polynomial::{CoeffOrder, Polynomial};
+use crate::report::SolveReport;
+use crate::solver::{HighPrecFloat, HyperCatalanPolynomialSolver};
+use crate::SolverError;
+
+/// Face levels of the series used per polynomial in the batch modes
+const STREAM_MAX_TERMS: usize = 20;
+
+/// Newton iterations used to polish the series root in the batch modes
+const STREAM_NEWTON_ITERATIONS: usize = 50;
+
+/// Token printed in place of a root for malformed or unsolvable lines
+pub const ERROR_TOKEN: &str = "ERROR";
+
+/// Header row written first in CSV output
+pub const CSV_HEADER: &str = "input,root,residual,converged";
+
+/// A root counts as converged when its residual is this small relative to `Σ|cᵢ||x|ⁱ`
+const CONVERGED_RELATIVE_RESIDUAL: HighPrecFloat = 1e-10;
+
+/// How the batch modes write their results
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    /// One root (or `ERROR`) per line
+    #[default]
+    Plain,
+    /// A CSV table with the columns of `CSV_HEADER`
+    Csv,
+}
+
+/// Quote a CSV field if it contains a comma, quote or line break
+pub fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Whether the residual is negligible relative to the size of the polynomial's terms at the root
+fn is_converged(coefficients: &[HighPrecFloat], report: &SolveReport) -> bool {
+    let magnitude: HighPrecFloat = coefficients
+        .iter()
+        .enumerate()
+        .map(|(i, c)| c.abs() * report.root.abs().powi(i as i32))
+        .sum();
+    report.residual <= CONVERGED_RELATIVE_RESIDUAL * magnitude
+}
+
+/// Attempts the interactive binary allows for each prompt before giving up
+pub const PROMPT_ATTEMPTS: usize = 3;
+
+/// Print `prompt` and read a value, reprinting the prompt after each parse failure
+///
+/// An empty line yields `default` when one is given (and counts as a parse failure
+/// otherwise). Returns `Ok(None)` after `attempts` failed tries or at end of input.
+pub fn prompt_parse<T: FromStr, R: BufRead, W: Write>(
+    input: &mut R,
+    output: &mut W,
+    prompt: &str,
+    default: Option<T>,
+    attempts: usize,
+) -> io::Result<Option<T>> {
+    let mut default = default;
+    for attempt in 1..=attempts {
+        write!(output, "{}", prompt)?;
+        output.flush()?;
+
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        let line = line.trim();
+        if line.is_empty() {
+            if let Some(value) = default.take() {
+                return Ok(Some(value));
+            }
+        } else if let Ok(value) = line.parse() {
+            return Ok(Some(value));
+        }
+
+        if attempt < attempts {
+            writeln!(output, "Invalid input '{}', please try again", line)?;
+        }
+    }
+    Ok(None)
+}
+
+/// Parse a comma-separated coefficient list into ascending order
+///
+/// Returns `None` if any field is not a number or the list is empty.
+pub fn parse_coefficient_line(line: &str, order: CoeffOrder) -> Option<Polynomial> {
+    let entered: Option<Vec<HighPrecFloat>> = line
+        .split(',')
+        .map(|field| field.trim().parse().ok())
+        .collect();
+    entered
+        .filter(|coefficients| !coefficients.is_empty())
+        .map(|coefficients| Polynomial::from_slice(&coefficients, order))
+}
+
+/// Solve one polynomial with the series, polished by Newton's method
+pub fn solve_line_polynomial(polynomial: &Polynomial) -> Result<HighPrecFloat, SolverError> {
+    let coefficients = &polynomial.coefficients;
+    let mut solver = HyperCatalanPolynomialSolver::for_coefficients(coefficients, STREAM_MAX_TERMS);
+    // Newton's method polishes the series root, so a rough seed is fine
+    solver.set_residual_tolerance(None);
+    let series_root = solver.solve_polynomial(coefficients)?;
+    Ok(solver.newton_root(coefficients, series_root, STREAM_NEWTON_ITERATIONS))
+}
+
+/// Solve one polynomial per input line, writing one result per line
+///
+/// Each line holds comma-separated coefficients in `order`. Blank lines are
+/// skipped. With `OutputFormat::Plain`, lines that fail to parse or solve produce
+/// `ERROR`; with `OutputFormat::Csv` a header is written first and such lines get
+/// empty root and residual fields and `converged` set to `false`. Processing
+/// always continues, and the output is flushed after every line so the binary
+/// works as a Unix filter.
+pub fn run_stream<R: BufRead, W: Write>(
+    input: R,
+    output: &mut W,
+    order: CoeffOrder,
+    format: OutputFormat,
+) -> io::Result<()> {
+    if format == OutputFormat::Csv {
+        writeln!(output, "{}", CSV_HEADER)?;
+        output.flush()?;
+    }
+
+    for line in input.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let solved = parse_coefficient_line(&line, order).and_then(|polynomial| {
+            let root = solve_line_polynomial(&polynomial).ok()?;
+            let report = SolveReport::for_root(&polynomial.coefficients, root);
+            let converged = is_converged(&polynomial.coefficients, &report);
+            Some((report, converged))
+        });
+
+        match (format, solved) {
+            (OutputFormat::Plain, Some((report, _))) => writeln!(output, "{}", report.root)?,
+            (OutputFormat::Plain, None) => writeln!(output, "{}", ERROR_TOKEN)?,
+            (OutputFormat::Csv, Some((report, converged))) => writeln!(
+                output,
+                "{},{},{},{}",
+                csv_escape(line.trim()),
+                report.root,
+                report.residual,
+                converged
+            )?,
+            (OutputFormat::Csv, None) => writeln!(output, "{},,,false", csv_escape(line.trim()))?,
+        }
+        output.flush()?;
+    }
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use num::Complex;
+
+use crate::polynomial::depress;
+use crate::solver::HighPrecFloat;
+
+/// Roots of `z² + bz + c = 0` over the complex numbers
+fn monic_quadratic_roots(b: Complex<f64>, c: Complex<f64>) -> [Complex<f64>; 2] {
+    let sqrt_discriminant = (b * b - c * 4.0).sqrt();
+    // Pick the sign that avoids cancellation, then use Vieta for the other root
+    let q = if (b.conj() * sqrt_discriminant).re >= 0.0 {
+        -(b + sqrt_discriminant) / 2.0
+    } else {
+        -(b - sqrt_discriminant) / 2.0
+    };
+    if q == Complex::new(0.0, 0.0) {
+        return [q, q];
+    }
+    [q, c / q]
+}
+
+/// Solve `c₀ + c₁x + c₂x² = 0` with the quadratic formula
+///
+/// # Panics
+///
+/// Panics unless exactly three coefficients are given with a nonzero `c₂`.
+pub fn solve_quadratic(coefficients: &[HighPrecFloat]) -> [Complex<f64>; 2] {
+    assert!(coefficients.len() == 3 && coefficients[2] != 0.0, "expected a quadratic");
+    let b = Complex::new(coefficients[1] / coefficients[2], 0.0);
+    let c = Complex::new(coefficients[0] / coefficients[2], 0.0);
+    monic_quadratic_roots(b, c)
+}
+
+/// Solve `c₀ + c₁x + c₂x² + c₃x³ = 0` with Cardano's formula
+///
+/// The cubic is first depressed to `y³ + py + q`, then solved in complex arithmetic
+/// so the three-real-roots ("casus irreducibilis") case needs no special handling;
+/// real roots may carry imaginary parts at rounding level.
+///
+/// # Panics
+///
+/// Panics unless exactly four coefficients are given with a nonzero `c₃`.
+pub fn solve_cubic(coefficients: &[HighPrecFloat]) -> [Complex<f64>; 3] {
+    assert!(coefficients.len() == 4 && coefficients[3] != 0.0, "expected a cubic");
+    let (depressed, shift) = depress(coefficients);
+    let p = Complex::new(depressed[1] / depressed[3], 0.0);
+    let q = Complex::new(depressed[0] / depressed[3], 0.0);
+
+    // y = u + v with u³ = -q/2 ± sqrt((q/2)² + (p/3)³) and uv = -p/3
+    let sqrt_discriminant = (q * q / 4.0 + p * p * p / 27.0).sqrt();
+    let plus = -q / 2.0 + sqrt_discriminant;
+    let minus = -q / 2.0 - sqrt_discriminant;
+    let u_cubed = if plus.norm() >= minus.norm() { plus } else { minus };
+    let u = u_cubed.cbrt();
+
+    let omega = Complex::new(-0.5, 3.0_f64.sqrt() / 2.0);
+    let mut roots = [Complex::new(0.0, 0.0); 3];
+    let mut rotation = Complex::new(1.0, 0.0);
+    for root in roots.iter_mut() {
+        let u_k = u * rotation;
+        let v_k = if u_k == Complex::new(0.0, 0.0) { u_k } else { -p / (u_k * 3.0) };
+        *root = u_k + v_k + shift;
+        rotation *= omega;
+    }
+    roots
+}
+
+/// Solve `c₀ + c₁x + c₂x² + c₃x³ + c₄x⁴ = 0` with Ferrari's method
+///
+/// The quartic is depressed to `y⁴ + py² + qy + r`. The biquadratic case `q = 0` is
+/// solved directly as a quadratic in `y²`; otherwise a root `m` of the resolvent cubic
+/// `8m³ + 8pm² + (2p² - 8r)m - q² = 0` (found with `solve_cubic`) splits the quartic
+/// into two quadratics.
+///
+/// # Panics
+///
+/// Panics unless exactly five coefficients are given with a nonzero `c₄`.
+pub fn solve_quartic(coefficients: &[HighPrecFloat]) -> [Complex<f64>; 4] {
+    assert!(coefficients.len() == 5 && coefficients[4] != 0.0, "expected a quartic");
+    let (depressed, shift) = depress(coefficients);
+    let p = depressed[2] / depressed[4];
+    let q = depressed[1] / depressed[4];
+    let r = depressed[0] / depressed[4];
+
+    let scale = 1.0 + p.abs() + r.abs();
+    let [y1, y2, y3, y4] = if q.abs() <= 1e-14 * scale {
+        let [z1, z2] = monic_quadratic_roots(Complex::new(p, 0.0), Complex::new(r, 0.0));
+        [z1.sqrt(), -z1.sqrt(), z2.sqrt(), -z2.sqrt()]
+    } else {
+        // Choose the resolvent root farthest from zero to keep sqrt(2m) well away from 0
+        let resolvent = solve_cubic(&[-q * q, 2.0 * p * p - 8.0 * r, 8.0 * p, 8.0]);
+        let m = resolvent
+            .iter()
+            .copied()
+            .max_by(|a, b| a.norm().total_cmp(&b.norm()))
+            .unwrap_or_default();
+
+        let s = (m * 2.0).sqrt();
+        let half_p = Complex::new(p / 2.0, 0.0) + m;
+        let correction = Complex::new(q, 0.0) / (s * 2.0);
+        let [a1, a2] = monic_quadratic_roots(s, half_p - correction);
+        let [b1, b2] = monic_quadratic_roots(-s, half_p + correction);
+        [a1, a2, b1, b2]
+    };
+
+    [y1 + shift, y2 + shift, y3 + shift, y4 + shift]
+}
@@ -0,0 +1,68 @@
+use std::ops::{Add, Mul, Sub};
+
+use crate::solver::HighPrecFloat;
+
+/// Dual number `value + deriv·ε` with `ε² = 0`, for forward-mode differentiation
+///
+/// Evaluating any expression built from `+`, `-` and `*` at `Dual::variable(x)`
+/// yields the expression's value and its exact derivative at `x` in one pass.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dual {
+    pub value: HighPrecFloat,
+    pub deriv: HighPrecFloat,
+}
+
+impl Dual {
+    /// Create a dual number from its value and derivative parts
+    pub fn new(value: HighPrecFloat, deriv: HighPrecFloat) -> Self {
+        Dual { value, deriv }
+    }
+
+    /// A constant, whose derivative is zero
+    pub fn constant(value: HighPrecFloat) -> Self {
+        Dual::new(value, 0.0)
+    }
+
+    /// The independent variable, whose derivative is one
+    pub fn variable(value: HighPrecFloat) -> Self {
+        Dual::new(value, 1.0)
+    }
+}
+
+impl Add for Dual {
+    type Output = Dual;
+
+    fn add(self, other: Dual) -> Dual {
+        Dual::new(self.value + other.value, self.deriv + other.deriv)
+    }
+}
+
+impl Sub for Dual {
+    type Output = Dual;
+
+    fn sub(self, other: Dual) -> Dual {
+        Dual::new(self.value - other.value, self.deriv - other.deriv)
+    }
+}
+
+impl Mul for Dual {
+    type Output = Dual;
+
+    fn mul(self, other: Dual) -> Dual {
+        Dual::new(
+            self.value * other.value,
+            self.value * other.deriv + self.deriv * other.value,
+        )
+    }
+}
+
+/// Evaluate a polynomial and its derivative at `x` in a single Horner pass
+///
+/// Returns `(p(x), p'(x))`, computed by running Horner's scheme over dual numbers.
+pub fn eval_dual(coefficients: &[HighPrecFloat], x: HighPrecFloat) -> (HighPrecFloat, HighPrecFloat) {
+    let x = Dual::variable(x);
+    let result = coefficients.iter()
+        .rev()
+        .fold(Dual::constant(0.0), |acc, &coeff| acc * x + Dual::constant(coeff));
+    (result.value, result.deriv)
+}
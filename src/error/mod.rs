@@ -0,0 +1,63 @@
+use std::fmt;
+
+use crate::solver::HighPrecFloat;
+
+/// Errors returned by the polynomial solver
+#[derive(Debug, Clone, PartialEq)]
+pub enum SolverError {
+    /// The polynomial must be at least of degree 1
+    DegreeTooLow,
+    /// The coefficient at `index` is NaN or infinite
+    InvalidCoefficient { index: usize },
+    /// Every coefficient is zero, so every x is a root
+    ZeroPolynomial,
+    /// The solver was configured with `max_terms = 0`, so the series has no terms
+    NoSeriesTerms,
+    /// The polynomial's degree exceeds the solver's configured maximum degree
+    DegreeTooHigh { degree: usize, max_degree: usize },
+    /// The coefficient of x¹ is zero, so the geometric form is undefined
+    ZeroLinearCoefficient,
+    /// The series summed to zero, which cannot be a root of `1 - a + t₂a² + ... = 0`
+    ZeroGeometricRoot,
+    /// Newton's method fell into a 2-cycle instead of converging; carries the last iterate
+    NewtonCycle { x: HighPrecFloat },
+    /// The series did not produce a usable root; carries the residual when one was computed
+    NotConverged { residual: Option<HighPrecFloat> },
+}
+
+impl fmt::Display for SolverError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolverError::DegreeTooLow => write!(f, "Polynomial must be at least of degree 1"),
+            SolverError::InvalidCoefficient { index } => {
+                write!(f, "Coefficient c{} is not a finite number", index)
+            }
+            SolverError::ZeroPolynomial => write!(f, "All coefficients are zero; every value is a root"),
+            SolverError::NoSeriesTerms => write!(f, "max_terms must be at least 1"),
+            SolverError::DegreeTooHigh { degree, max_degree } => write!(
+                f,
+                "Polynomial of degree {} exceeds the solver's maximum degree {}",
+                degree, max_degree
+            ),
+            SolverError::ZeroLinearCoefficient => write!(
+                f,
+                "Coefficient for x^1 cannot be zero for geometric form conversion"
+            ),
+            SolverError::ZeroGeometricRoot => write!(
+                f,
+                "Series summed to zero, which is not a root of the geometric form"
+            ),
+            SolverError::NewtonCycle { x } => {
+                write!(f, "Newton's method is cycling (last iterate {})", x)
+            }
+            SolverError::NotConverged { residual: Some(residual) } => {
+                write!(f, "Series did not converge (residual {})", residual)
+            }
+            SolverError::NotConverged { residual: None } => {
+                write!(f, "Series did not converge within the work budget")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SolverError {}
@@ -0,0 +1,118 @@
+//! A common interface over the crate's root-finding methods
+
+use num::Complex;
+
+use crate::roots::{durand_kerner, solve_all_real_poly};
+use crate::solver::{HighPrecFloat, HyperCatalanPolynomialSolver};
+
+/// A method that finds roots of a polynomial given constant term first
+///
+/// Implementations return every root they find; methods restricted to real roots
+/// return them with zero imaginary part, and the order is method specific.
+pub trait RootFinder {
+    /// Short name of the method, for logs and comparisons
+    fn name(&self) -> &str;
+
+    /// Find roots of `c₀ + c₁x + ... + cₙxⁿ`
+    fn find_roots(&self, coefficients: &[HighPrecFloat]) -> Vec<Complex<f64>>;
+}
+
+/// The Hyper-Catalan series seeding Newton's method, with deflation (`solve_all_roots`)
+///
+/// Finds real roots only. Works on a clone of the solver, since solving updates the
+/// Hyper-Catalan cache.
+impl RootFinder for HyperCatalanPolynomialSolver {
+    fn name(&self) -> &str {
+        "hyper-catalan"
+    }
+
+    fn find_roots(&self, coefficients: &[HighPrecFloat]) -> Vec<Complex<f64>> {
+        self.clone()
+            .solve_all_roots(coefficients)
+            .into_iter()
+            .map(|root| Complex::new(root, 0.0))
+            .collect()
+    }
+}
+
+/// Laguerre's method with deflation on the balanced polynomial (`solve_all_real_poly`)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LaguerreFinder;
+
+impl RootFinder for LaguerreFinder {
+    fn name(&self) -> &str {
+        "laguerre"
+    }
+
+    fn find_roots(&self, coefficients: &[HighPrecFloat]) -> Vec<Complex<f64>> {
+        solve_all_real_poly(coefficients)
+    }
+}
+
+/// Homotopy continuation from `xⁿ - 1` (`HyperCatalanPolynomialSolver::solve_homotopy`)
+#[derive(Debug, Clone, Copy)]
+pub struct HomotopyFinder {
+    /// Number of continuation steps
+    pub steps: usize,
+}
+
+impl RootFinder for HomotopyFinder {
+    fn name(&self) -> &str {
+        "homotopy"
+    }
+
+    fn find_roots(&self, coefficients: &[HighPrecFloat]) -> Vec<Complex<f64>> {
+        let complex: Vec<Complex<f64>> = coefficients.iter().map(|&c| Complex::new(c, 0.0)).collect();
+        HyperCatalanPolynomialSolver::default().solve_homotopy(&complex, self.steps)
+    }
+}
+
+/// Newton's method from seeded random guesses (`find_roots_random_restart`)
+///
+/// Finds real roots only.
+#[derive(Debug, Clone, Copy)]
+pub struct RandomRestartFinder {
+    /// Number of random starting points
+    pub attempts: usize,
+    /// Seed for the guess generator
+    pub seed: u64,
+}
+
+impl RootFinder for RandomRestartFinder {
+    fn name(&self) -> &str {
+        "random-restart"
+    }
+
+    fn find_roots(&self, coefficients: &[HighPrecFloat]) -> Vec<Complex<f64>> {
+        HyperCatalanPolynomialSolver::default()
+            .find_roots_random_restart(coefficients, self.attempts, self.seed)
+            .into_iter()
+            .map(|root| Complex::new(root, 0.0))
+            .collect()
+    }
+}
+
+/// Simultaneous iteration on all roots (`durand_kerner`)
+#[derive(Debug, Clone, Copy)]
+pub struct DurandKernerFinder {
+    /// Maximum number of sweeps over all estimates
+    pub max_iterations: usize,
+    /// Largest relative step at which the iteration is considered converged
+    pub tolerance: HighPrecFloat,
+}
+
+impl Default for DurandKernerFinder {
+    fn default() -> Self {
+        DurandKernerFinder { max_iterations: 500, tolerance: 1e-14 }
+    }
+}
+
+impl RootFinder for DurandKernerFinder {
+    fn name(&self) -> &str {
+        "durand-kerner"
+    }
+
+    fn find_roots(&self, coefficients: &[HighPrecFloat]) -> Vec<Complex<f64>> {
+        durand_kerner(coefficients, self.max_iterations, self.tolerance)
+    }
+}
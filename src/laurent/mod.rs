@@ -0,0 +1,35 @@
+use crate::polynomial::Polynomial;
+use crate::solver::HighPrecFloat;
+
+/// Laurent polynomial `Σ coefficients[k] · x^(min_degree + k)`, allowing negative powers
+#[derive(Debug, Clone, PartialEq)]
+pub struct LaurentPolynomial {
+    /// Power of x multiplying the first coefficient; may be negative
+    pub min_degree: i32,
+    pub coefficients: Vec<HighPrecFloat>,
+}
+
+impl LaurentPolynomial {
+    /// Create a Laurent polynomial whose first coefficient multiplies `x^min_degree`
+    pub fn new(min_degree: i32, coefficients: Vec<HighPrecFloat>) -> Self {
+        LaurentPolynomial { min_degree, coefficients }
+    }
+
+    /// Evaluate at `x`, or `None` at `x = 0` when there are negative powers
+    pub fn eval(&self, x: HighPrecFloat) -> Option<HighPrecFloat> {
+        if x == 0.0 && self.min_degree < 0 {
+            return None;
+        }
+        let inner = self.coefficients.iter().rev().fold(0.0, |acc, &c| acc * x + c);
+        Some(inner * x.powi(self.min_degree))
+    }
+
+    /// The polynomial `x^(-min_degree) · L(x)`, with the same nonzero roots
+    ///
+    /// For `min_degree < 0` this clears the negative powers; multiplying through
+    /// does not add roots other than possibly `x = 0`. For `min_degree > 0` the
+    /// leading factor `x^min_degree` (a root at zero) is dropped.
+    pub fn to_polynomial(&self) -> Polynomial {
+        Polynomial::new(self.coefficients.clone())
+    }
+}
@@ -1,13 +1,36 @@
 pub mod subdigon;
 pub mod calculator;
 pub mod solver;
+pub mod error;
+pub mod polynomial;
+pub mod modular;
+pub mod rational;
+pub mod closed_form;
+pub mod roots;
+pub mod report;
+pub mod dual;
+pub mod rng;
+pub mod verify;
+pub mod system;
+pub mod bivariate;
+pub mod cli;
+pub mod laurent;
+pub mod acceleration;
+pub mod finder;
+#[cfg(feature = "tokio")]
+pub mod async_solver;
 pub mod tests;
 
+use num::Complex;
+
 // Re-export commonly used types
 pub use solver::HighPrecFloat;
 pub use solver::HyperCatalanPolynomialSolver;
+pub use error::SolverError;
+pub use report::SolveReport;
 pub use calculator::HyperCatalanCalculator;
 pub use subdigon::SubdigonType;
+pub use polynomial::{CoeffOrder, Polynomial};
 
 // Convenience function to evaluate a polynomial at a specific point
 pub fn evaluate_polynomial(coefficients: &[HighPrecFloat], x: HighPrecFloat) -> HighPrecFloat {
@@ -16,4 +39,68 @@ pub fn evaluate_polynomial(coefficients: &[HighPrecFloat], x: HighPrecFloat) ->
         result += coeff * x.powi(i as i32);
     }
     result
-} 
\ No newline at end of file
+}
+
+// Evaluate a polynomial with a compile-time number of coefficients (Horner's scheme).
+// The loop bound is the constant N, so the compiler unrolls it without bounds checks;
+// meant for hot loops over quadratics and cubics. N = 0 evaluates to 0.
+pub fn evaluate_fixed<const N: usize>(coefficients: &[HighPrecFloat; N], x: HighPrecFloat) -> HighPrecFloat {
+    let mut result = 0.0;
+    for i in (0..N).rev() {
+        result = result * x + coefficients[i];
+    }
+    result
+}
+
+// Evaluate a polynomial at a possibly huge argument without intermediate overflow.
+// For |x| > 1 the dominant power is factored out, p(x) = xⁿ·(cₙ + cₙ₋₁/x + ... + c₀/xⁿ),
+// and the two factors are combined through logarithms when xⁿ alone would overflow.
+// Values beyond the f64 range saturate to ±f64::MAX with the correct sign.
+pub fn evaluate_polynomial_scaled(coefficients: &[HighPrecFloat], x: HighPrecFloat) -> HighPrecFloat {
+    let n = match coefficients.iter().rposition(|&c| c != 0.0) {
+        Some(n) => n,
+        None => return 0.0,
+    };
+    if x.abs() <= 1.0 {
+        return coefficients[..=n].iter().rev().fold(0.0, |acc, &c| acc * x + c);
+    }
+
+    let y = 1.0 / x;
+    let scaled = coefficients[..=n].iter().fold(0.0, |acc, &c| acc * y + c);
+    let power = x.powi(n as i32);
+    let value = scaled * power;
+    if value.is_finite() && power.is_finite() {
+        return value;
+    }
+
+    let sign = scaled.signum() * if x < 0.0 && n % 2 == 1 { -1.0 } else { 1.0 };
+    let magnitude = (scaled.abs().ln() + n as HighPrecFloat * x.abs().ln()).exp();
+    sign * magnitude.min(HighPrecFloat::MAX)
+}
+
+// Convenience function to evaluate the derivative of a polynomial at a specific point
+pub fn evaluate_derivative(coefficients: &[HighPrecFloat], x: HighPrecFloat) -> HighPrecFloat {
+    let mut result = 0.0;
+    for (i, &coeff) in coefficients.iter().enumerate().skip(1) {
+        result += (i as HighPrecFloat) * coeff * x.powi(i as i32 - 1);
+    }
+    result
+}
+
+// Convenience function to evaluate a complex polynomial at a complex point (Horner's scheme)
+pub fn evaluate_polynomial_complex(coefficients: &[Complex<f64>], x: Complex<f64>) -> Complex<f64> {
+    coefficients
+        .iter()
+        .rev()
+        .fold(Complex::new(0.0, 0.0), |acc, &coeff| acc * x + coeff)
+}
+
+// Convenience function to evaluate the derivative of a complex polynomial at a complex point
+pub fn evaluate_derivative_complex(coefficients: &[Complex<f64>], x: Complex<f64>) -> Complex<f64> {
+    coefficients
+        .iter()
+        .enumerate()
+        .skip(1)
+        .rev()
+        .fold(Complex::new(0.0, 0.0), |acc, (i, &coeff)| acc * x + coeff * i as f64)
+}
@@ -1,73 +1,103 @@
-use std::io::{self, Write};
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+use clap::Parser;
+use hyper_catalan_rs::cli::{self, OutputFormat};
 use hyper_catalan_rs::{
-    HighPrecFloat, HyperCatalanPolynomialSolver, evaluate_polynomial
+    CoeffOrder, HighPrecFloat, HyperCatalanPolynomialSolver, Polynomial, evaluate_polynomial
 };
 
+/// Command line options
+#[derive(Parser, Debug)]
+#[command(version, about)]
+struct Args {
+    /// Order of the entered coefficients: ascending (constant term first) or descending
+    #[arg(long, default_value = "ascending")]
+    order: CoeffOrder,
+
+    /// Read comma-separated coefficients from stdin, one polynomial per line, and
+    /// print one root per line
+    #[arg(long)]
+    stream: bool,
+
+    /// Like --stream, but print a CSV table of input, root, residual and convergence
+    #[arg(long)]
+    csv: bool,
+}
+
 fn main() {
+    let args = Args::parse();
+
+    if args.stream || args.csv {
+        let format = if args.csv { OutputFormat::Csv } else { OutputFormat::Plain };
+        let stdin = io::stdin();
+        let mut stdout = io::stdout();
+        if let Err(e) = cli::run_stream(stdin.lock(), &mut stdout, args.order, format) {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
     // Print banner
     println!("Hyper-Catalan Series Polynomial Solver (Rust)");
     println!("Based on 'A Hyper-Catalan Series Solution to Polynomial Equations, and the Geode'");
     println!("by N.J. Wildberger and K.W. Rubin");
     println!("------------------------------------------------");
 
+    let stdin = io::stdin();
+    let mut input = stdin.lock();
+    let mut output = io::stdout();
+
     // Get polynomial degree
-    print!("Enter the degree of polynomial: ");
-    io::stdout().flush().unwrap();
-    
-    let mut input = String::new();
-    io::stdin().read_line(&mut input).expect("Failed to read input");
-    let degree: usize = input.trim().parse().expect("Please enter a valid degree");
-    
-    // Get coefficients
-    println!("Enter coefficients from c₀ to c{} (constant term first):", degree);
-    
-    let mut coefficients = Vec::with_capacity(degree + 1);
-    for i in 0..=degree {
-        print!("c{}: ", i);
-        io::stdout().flush().unwrap();
-        
-        let mut input = String::new();
-        io::stdin().read_line(&mut input).expect("Failed to read input");
-        let coeff: HighPrecFloat = input.trim().parse().expect("Please enter a valid number");
-        
-        coefficients.push(coeff);
+    let degree: usize = ask(&mut input, &mut output, "Enter the degree of polynomial: ", None);
+
+    // Get coefficients in the requested order
+    let indices: Vec<usize> = match args.order {
+        CoeffOrder::Ascending => {
+            println!("Enter coefficients from c₀ to c{} (constant term first):", degree);
+            (0..=degree).collect()
+        }
+        CoeffOrder::Descending => {
+            println!("Enter coefficients from c{} to c₀ (leading term first):", degree);
+            (0..=degree).rev().collect()
+        }
+    };
+
+    let mut entered = Vec::with_capacity(degree + 1);
+    for i in indices {
+        entered.push(ask(&mut input, &mut output, &format!("c{}: ", i), None));
     }
-    
+
+    let polynomial = Polynomial::from_slice(&entered, args.order);
+    let coefficients = polynomial.coefficients.clone();
+    println!("Solving: {} = 0", polynomial);
+
     // Create solver
-    let mut solver = HyperCatalanPolynomialSolver::new(degree, 20);
-    
+    let mut solver = HyperCatalanPolynomialSolver::for_coefficients(&coefficients, 20);
+
     // Use Hyper-Catalan series
     match solver.solve_polynomial(&coefficients) {
         Ok(series_root) => {
             println!("Root by Hyper-Catalan series: {}", series_root);
-            
+
             // Bootstrap with Newton's method
-            print!("Enter initial guess for bootstrap method (default: {}): ", series_root);
-            io::stdout().flush().unwrap();
-            
-            let mut input = String::new();
-            io::stdin().read_line(&mut input).expect("Failed to read input");
-            let initial_guess = if input.trim().is_empty() {
-                series_root
-            } else {
-                input.trim().parse().expect("Please enter a valid number")
-            };
-            
-            print!("Enter number of iterations for bootstrap method (default: 10): ");
-            io::stdout().flush().unwrap();
-            
-            let mut input = String::new();
-            io::stdin().read_line(&mut input).expect("Failed to read input");
-            let iterations: usize = if input.trim().is_empty() {
-                10 // Default value
-            } else {
-                input.trim().parse().expect("Please enter a valid number")
-            };
-            
+            let initial_guess: HighPrecFloat = ask(
+                &mut input,
+                &mut output,
+                &format!("Enter initial guess for bootstrap method (default: {}): ", series_root),
+                Some(series_root),
+            );
+            let iterations: usize = ask(
+                &mut input,
+                &mut output,
+                "Enter number of iterations for bootstrap method (default: 10): ",
+                Some(10),
+            );
+
             const EPSILON: HighPrecFloat = 1e-15;
             let bootstrap_root = solver.bootstrap_root(&coefficients, initial_guess, iterations, EPSILON);
             println!("Root by bootstrap method: {}", bootstrap_root);
-            
+
             // Check accuracy
             let error = evaluate_polynomial(&coefficients, bootstrap_root).abs();
             println!("Error: {}", error);
@@ -75,3 +105,19 @@ fn main() {
         Err(e) => println!("Error: {}", e),
     }
 }
+
+/// Prompt for a value with retries, exiting cleanly when input is exhausted or keeps failing to parse
+fn ask<T: FromStr>(input: &mut impl BufRead, output: &mut impl Write, prompt: &str, default: Option<T>) -> T {
+    match cli::prompt_parse(input, output, prompt, default, cli::PROMPT_ATTEMPTS) {
+        Ok(Some(value)) => value,
+        Ok(None) => {
+            println!();
+            println!("No valid input after {} attempts, exiting", cli::PROMPT_ATTEMPTS);
+            std::process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Failed to read input: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,33 @@
+/// Reduce a signed coefficient into the range `0..p`
+fn reduce(coefficient: i64, p: u64) -> u128 {
+    (coefficient as i128).rem_euclid(p as i128) as u128
+}
+
+/// Evaluate a polynomial modulo `p` at `x` using Horner's scheme
+///
+/// Coefficients are given constant term first, like `evaluate_polynomial`.
+/// Intermediate products are carried in `u128`, so any `p < 2^64` is safe.
+pub fn evaluate_polynomial_mod(coefficients: &[i64], x: u64, p: u64) -> u64 {
+    let modulus = p as u128;
+    let x = x as u128 % modulus;
+    let mut result = 0u128;
+    for &coeff in coefficients.iter().rev() {
+        result = (result * x + reduce(coeff, p)) % modulus;
+    }
+    result as u64
+}
+
+/// Find all roots of a polynomial over the finite field GF(p)
+///
+/// Evaluates the polynomial at every residue, so the cost is `O(p * degree)`;
+/// this is intended for small and moderate primes. The roots are returned in
+/// increasing order. `p` is assumed to be prime.
+pub fn roots_mod_p(coefficients: &[i64], p: u64) -> Vec<u64> {
+    if p == 0 {
+        return Vec::new();
+    }
+
+    (0..p)
+        .filter(|&x| evaluate_polynomial_mod(coefficients, x, p) == 0)
+        .collect()
+}
@@ -0,0 +1,573 @@
+use std::fmt;
+use std::iter::Enumerate;
+use std::ops::Index;
+use std::slice;
+use std::str::FromStr;
+
+use num::Complex;
+
+#[cfg(feature = "nalgebra")]
+use nalgebra::DMatrix;
+
+use crate::{evaluate_derivative, evaluate_polynomial};
+use crate::roots::{solve_all_real_poly, sort_roots};
+use crate::solver::HighPrecFloat;
+
+/// A univariate polynomial with real coefficients
+/// coefficients[0] is the constant term, coefficients[1] the coefficient of x, etc.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polynomial {
+    pub coefficients: Vec<HighPrecFloat>,
+}
+
+/// Order in which a coefficient list is written
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CoeffOrder {
+    /// Constant term first: `[c₀, c₁, ..., cₙ]` (the crate's native order)
+    #[default]
+    Ascending,
+    /// Leading term first: `[cₙ, ..., c₁, c₀]`, as in most textbooks
+    Descending,
+}
+
+impl FromStr for CoeffOrder {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "ascending" | "asc" => Ok(CoeffOrder::Ascending),
+            "descending" | "desc" => Ok(CoeffOrder::Descending),
+            _ => Err(format!("Unknown coefficient order '{}', expected ascending or descending", s)),
+        }
+    }
+}
+
+impl Polynomial {
+    /// Create a new polynomial from coefficients, constant term first
+    pub fn new(coefficients: Vec<HighPrecFloat>) -> Self {
+        Polynomial { coefficients }
+    }
+
+    /// Create a polynomial from coefficients written in the given order
+    pub fn from_slice(coeffs: &[HighPrecFloat], order: CoeffOrder) -> Self {
+        let mut coefficients = coeffs.to_vec();
+        if order == CoeffOrder::Descending {
+            coefficients.reverse();
+        }
+        Polynomial { coefficients }
+    }
+
+    /// Degree of the polynomial, ignoring zero high-order coefficients (0 for constants)
+    pub fn degree(&self) -> usize {
+        self.coefficients.iter().rposition(|&c| c != 0.0).unwrap_or(0)
+    }
+
+    /// Iterate over `(power, coefficient)` pairs, constant term first
+    ///
+    /// Every stored coefficient is visited, including zeros.
+    pub fn iter(&self) -> Terms<'_> {
+        Terms { inner: self.coefficients.iter().enumerate() }
+    }
+
+    /// Evaluate the polynomial at x
+    pub fn evaluate(&self, x: HighPrecFloat) -> HighPrecFloat {
+        evaluate_polynomial(&self.coefficients, x)
+    }
+
+    /// Parse a polynomial in `x` written as a sum of terms, such as `3x^2 - 2.5*x + 1`
+    ///
+    /// Terms may appear in any order and repeated powers are added together. A term is
+    /// an optional coefficient (with an optional `*`), optionally followed by `x` and
+    /// `^n`; whitespace is ignored. This accepts everything `Display` prints.
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let compact: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+        if compact.is_empty() {
+            return Err("Empty polynomial".to_string());
+        }
+
+        // Split before every sign that is not part of an exponent like 1e-5
+        let mut terms = Vec::new();
+        let mut start = 0;
+        let bytes = compact.as_bytes();
+        for (i, &b) in bytes.iter().enumerate() {
+            if (b == b'+' || b == b'-') && i > start && !matches!(bytes[i - 1], b'e' | b'E') {
+                terms.push(&compact[start..i]);
+                start = i;
+            }
+        }
+        terms.push(&compact[start..]);
+
+        let mut coefficients = Vec::new();
+        for term in terms {
+            let (power, coefficient) = parse_term(term)?;
+            if coefficients.len() <= power {
+                coefficients.resize(power + 1, 0.0);
+            }
+            coefficients[power] += coefficient;
+        }
+        Ok(Polynomial { coefficients })
+    }
+}
+
+/// Parse one signed term like `-3x^2`, `+x` or `2.5`, returning `(power, coefficient)`
+fn parse_term(term: &str) -> Result<(usize, HighPrecFloat), String> {
+    let invalid = || format!("Invalid term '{}'", term);
+    let (sign, body) = match term.as_bytes().first() {
+        Some(b'-') => (-1.0, &term[1..]),
+        Some(b'+') => (1.0, &term[1..]),
+        _ => (1.0, term),
+    };
+
+    let (coefficient, power) = match body.split_once('x') {
+        None => (body, None),
+        Some((coefficient, rest)) => {
+            let coefficient = coefficient.strip_suffix('*').unwrap_or(coefficient);
+            let power = match rest {
+                "" => 1,
+                _ => rest.strip_prefix('^')
+                    .and_then(|p| p.parse().ok())
+                    .ok_or_else(invalid)?,
+            };
+            (coefficient, Some(power))
+        }
+    };
+
+    let value = match (coefficient, power) {
+        ("", Some(_)) => 1.0,
+        _ => coefficient.parse::<HighPrecFloat>().map_err(|_| invalid())?,
+    };
+    Ok((power.unwrap_or(0), sign * value))
+}
+
+/// The coefficient of `x^i`, constant term first: `p[0]` is `c₀`
+///
+/// Powers beyond the stored coefficients have coefficient 0.
+impl Index<usize> for Polynomial {
+    type Output = HighPrecFloat;
+
+    fn index(&self, power: usize) -> &HighPrecFloat {
+        const ZERO: HighPrecFloat = 0.0;
+        self.coefficients.get(power).unwrap_or(&ZERO)
+    }
+}
+
+/// Iterator over `(power, coefficient)` pairs of a polynomial, constant term first
+#[derive(Debug, Clone)]
+pub struct Terms<'a> {
+    inner: Enumerate<slice::Iter<'a, HighPrecFloat>>,
+}
+
+impl Iterator for Terms<'_> {
+    type Item = (usize, HighPrecFloat);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(power, &coefficient)| (power, coefficient))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a> IntoIterator for &'a Polynomial {
+    type Item = (usize, HighPrecFloat);
+    type IntoIter = Terms<'a>;
+
+    fn into_iter(self) -> Terms<'a> {
+        self.iter()
+    }
+}
+
+/// Parses with `Polynomial::parse`
+///
+/// ```
+/// use hyper_catalan_rs::Polynomial;
+///
+/// let p: Polynomial = "x^2 - 4".parse().unwrap();
+/// assert_eq!(p.coefficients, vec![-4.0, 0.0, 1.0]);
+/// ```
+impl FromStr for Polynomial {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Polynomial::parse(s)
+    }
+}
+
+/// Formats like `x^3 -6x^2 +11x -6`, highest degree first
+impl fmt::Display for Polynomial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", format_polynomial(&self.coefficients))
+    }
+}
+
+/// Format a polynomial given constant term first, e.g. `x^3 -6x^2 +11x -6`
+///
+/// Terms run from the highest degree down, zero terms are omitted, coefficients of
+/// `1` and `-1` are written as a bare sign, and `x^1`/`x^0` are written as `x` and
+/// the constant. Every term after the first carries an explicit sign. A polynomial
+/// with no nonzero coefficients formats as `0`.
+pub fn format_polynomial(coefficients: &[HighPrecFloat]) -> String {
+    let mut terms = Vec::new();
+    for (i, &coeff) in coefficients.iter().enumerate().rev() {
+        if coeff == 0.0 {
+            continue;
+        }
+
+        let sign = if coeff > 0.0 && !terms.is_empty() { "+" } else { "" };
+        let term = if i == 0 {
+            format!("{}{}", sign, coeff)
+        } else {
+            let power = if i > 1 { format!("x^{}", i) } else { "x".to_string() };
+            if coeff == 1.0 {
+                format!("{}{}", sign, power)
+            } else if coeff == -1.0 {
+                format!("-{}", power)
+            } else {
+                format!("{}{}{}", sign, coeff, power)
+            }
+        };
+        terms.push(term);
+    }
+
+    if terms.is_empty() {
+        return "0".to_string();
+    }
+    terms.join(" ")
+}
+
+/// Rescale a polynomial via `x -> s*y` to minimize the coefficient dynamic range
+///
+/// Fits a line through `log2|c_i|` over the nonzero coefficients (a geometric-mean
+/// heuristic) and picks `s` as the power of two that flattens it, so the rescaling
+/// itself introduces no rounding error. The result is also divided by a power of two
+/// bringing the geometric mean of the coefficient magnitudes close to 1.
+///
+/// Returns the rescaled coefficients and `s`; a root `y` of the rescaled polynomial
+/// corresponds to the root `x = s * y` of the original.
+pub fn balance_coefficients(coefficients: &[HighPrecFloat]) -> (Vec<HighPrecFloat>, f64) {
+    let points: Vec<(f64, f64)> = coefficients.iter()
+        .enumerate()
+        .filter(|(_, &c)| c != 0.0 && c.is_finite())
+        .map(|(i, &c)| (i as f64, c.abs().log2()))
+        .collect();
+
+    if points.len() < 2 {
+        return (coefficients.to_vec(), 1.0);
+    }
+
+    // Least-squares slope of log2|c_i| against i
+    let n = points.len() as f64;
+    let mean_i = points.iter().map(|&(i, _)| i).sum::<f64>() / n;
+    let mean_log = points.iter().map(|&(_, l)| l).sum::<f64>() / n;
+    let mut covariance = 0.0;
+    let mut variance = 0.0;
+    for &(i, l) in &points {
+        covariance += (i - mean_i) * (l - mean_log);
+        variance += (i - mean_i) * (i - mean_i);
+    }
+    let slope = covariance / variance;
+
+    // Substituting x = s*y multiplies c_i by s^i, adding i*log2(s) to each log
+    let exponent = (-slope).round() as i32;
+    let scale = 2.0_f64.powi(exponent);
+
+    // Mean log-magnitude after rescaling, used to normalize the whole polynomial
+    let shifted_mean = mean_log + exponent as f64 * mean_i;
+    let normalizer = 2.0_f64.powi(-(shifted_mean.round() as i32));
+
+    let balanced = coefficients.iter()
+        .enumerate()
+        .map(|(i, &c)| c * scale.powi(i as i32) * normalizer)
+        .collect();
+
+    (balanced, scale)
+}
+
+/// Evaluate a polynomial at a square matrix argument, `p(A) = c₀I + c₁A + c₂A² + ...`
+///
+/// Uses Horner's scheme with matrix multiplication, so only `degree` products are
+/// needed. Returns an error if `a` is not square.
+#[cfg(feature = "nalgebra")]
+pub fn evaluate_polynomial_matrix(
+    coefficients: &[HighPrecFloat],
+    a: &DMatrix<HighPrecFloat>,
+) -> Result<DMatrix<HighPrecFloat>, String> {
+    if !a.is_square() {
+        return Err(format!(
+            "Matrix argument must be square, got {}x{}",
+            a.nrows(),
+            a.ncols()
+        ));
+    }
+
+    let n = a.nrows();
+    let identity = DMatrix::<HighPrecFloat>::identity(n, n);
+    let mut result = DMatrix::<HighPrecFloat>::zeros(n, n);
+    for &coeff in coefficients.iter().rev() {
+        result = &result * a + &identity * coeff;
+    }
+    Ok(result)
+}
+
+/// Compute the Taylor coefficients `p⁽ᵏ⁾(r)/k!` for every `k` at once
+///
+/// Uses repeated synthetic division by `(x - r)`, which costs `O(n²)` operations: the
+/// `k`-th remainder is the `k`-th coefficient. Entry 0 is `p(r)` and entry 1 is `p'(r)`.
+pub fn taylor_coefficients(coefficients: &[HighPrecFloat], r: HighPrecFloat) -> Vec<HighPrecFloat> {
+    let mut taylor = coefficients.to_vec();
+    let n = taylor.len();
+    for i in 0..n {
+        for j in (i..n - 1).rev() {
+            taylor[j] += r * taylor[j + 1];
+        }
+    }
+    taylor
+}
+
+/// Compute the coefficients of the shifted polynomial `q(y) = p(y + h)`
+///
+/// These are the Taylor coefficients of `p` at `h`; see `taylor_coefficients`.
+pub fn taylor_shift(coefficients: &[HighPrecFloat], h: HighPrecFloat) -> Vec<HighPrecFloat> {
+    taylor_coefficients(coefficients, h)
+}
+
+/// Remove the `x^{n-1}` term with the Tschirnhaus substitution `x = y - c_{n-1}/(n·c_n)`
+///
+/// Returns the depressed polynomial in `y` and the shift `h = -c_{n-1}/(n·c_n)`, so
+/// each root `y` of the depressed polynomial gives the root `x = y + h` of the original.
+/// Polynomials of degree below 1 are returned unchanged with a zero shift.
+pub fn depress(coefficients: &[HighPrecFloat]) -> (Vec<HighPrecFloat>, HighPrecFloat) {
+    let len = coefficients.iter().rposition(|&c| c != 0.0).map_or(0, |i| i + 1);
+    if len < 2 {
+        return (coefficients.to_vec(), 0.0);
+    }
+
+    let n = len - 1;
+    let shift = -coefficients[n - 1] / (n as HighPrecFloat * coefficients[n]);
+    let mut depressed = taylor_shift(&coefficients[..len], shift);
+    // The x^{n-1} coefficient vanishes analytically; clear the rounding residue
+    depressed[n - 1] = 0.0;
+    (depressed, shift)
+}
+
+/// Sensitivity of a simple root to each coefficient, `∂root/∂cᵢ = -rootⁱ / p'(root)`
+///
+/// Follows from implicitly differentiating `p(root) = 0`. To first order, perturbing
+/// the coefficients by `δcᵢ` moves the root by `Σ sensitivityᵢ · δcᵢ`. The values are
+/// infinite at a multiple root, where `p'(root) = 0`.
+pub fn root_sensitivity(coefficients: &[HighPrecFloat], root: HighPrecFloat) -> Vec<HighPrecFloat> {
+    let derivative = evaluate_derivative(coefficients, root);
+    (0..coefficients.len())
+        .map(|i| -root.powi(i as i32) / derivative)
+        .collect()
+}
+
+/// Partial fraction decomposition of `numerator(x) / Π(x - rᵢ)` over simple roots
+///
+/// Returns `(rᵢ, residueᵢ)` pairs such that the rational function equals
+/// `Σ residueᵢ / (x - rᵢ)` (plus a polynomial part when the numerator's degree is not
+/// below the number of roots). Each residue is `numerator(rᵢ) / Π_{j≠i}(rᵢ - rⱼ)`.
+/// The roots must be distinct; repeated roots give infinite residues.
+pub fn partial_fractions(numerator: &Polynomial, roots: &[HighPrecFloat]) -> Vec<(HighPrecFloat, HighPrecFloat)> {
+    roots.iter()
+        .enumerate()
+        .map(|(i, &root)| {
+            let denominator: HighPrecFloat = roots.iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(_, &other)| root - other)
+                .product();
+            (root, numerator.evaluate(root) / denominator)
+        })
+        .collect()
+}
+
+/// Evaluate the rational function `numerator(x) / denominator(x)` with Horner's scheme
+///
+/// Returns `None` when the denominator is zero up to rounding, i.e. no larger than
+/// machine epsilon times `Σ|dᵢ||x|ⁱ`; removable singularities are not cancelled.
+pub fn evaluate_rational(
+    numerator: &[HighPrecFloat],
+    denominator: &[HighPrecFloat],
+    x: HighPrecFloat,
+) -> Option<HighPrecFloat> {
+    let horner = |coefficients: &[HighPrecFloat], x: HighPrecFloat| {
+        coefficients.iter().rev().fold(0.0, |acc, &c| acc * x + c)
+    };
+    let den = horner(denominator, x);
+    let magnitude: HighPrecFloat = denominator.iter().rev().fold(0.0, |acc, &c| acc * x.abs() + c.abs());
+    if den.abs() <= HighPrecFloat::EPSILON * magnitude {
+        return None;
+    }
+    Some(horner(numerator, x) / den)
+}
+
+/// Cauchy's bound: every root satisfies `|x| ≤ 1 + max_{i<n} |cᵢ / cₙ|`
+///
+/// Zero high-order coefficients are ignored; constant polynomials give 1.
+pub fn cauchy_bound(coefficients: &[HighPrecFloat]) -> HighPrecFloat {
+    let degree = match coefficients.iter().rposition(|&c| c != 0.0) {
+        Some(degree) => degree,
+        None => return 1.0,
+    };
+    let leading = coefficients[degree].abs();
+    1.0 + coefficients[..degree].iter()
+        .map(|c| c.abs() / leading)
+        .fold(0.0, HighPrecFloat::max)
+}
+
+/// Whether the coefficients read the same in both directions, `cᵢ = cₙ₋ᵢ`
+///
+/// Zero high-order coefficients are ignored and the comparison is exact. The roots of
+/// a palindromic polynomial come in reciprocal pairs `x`, `1/x`.
+pub fn is_palindromic(coefficients: &[HighPrecFloat]) -> bool {
+    let len = coefficients.iter().rposition(|&c| c != 0.0).map_or(0, |i| i + 1);
+    let trimmed = &coefficients[..len];
+    !trimmed.is_empty() && trimmed.iter().eq(trimmed.iter().rev())
+}
+
+/// Halve the degree of an even-degree palindromic polynomial with `y = x + 1/x`
+///
+/// For degree `2m`, `x⁻ᵐ·p(x) = cₘ + Σₖ cₘ₊ₖ(xᵏ + x⁻ᵏ)`, and each `xᵏ + x⁻ᵏ` is a
+/// polynomial in `y` (`y² - 2`, `y³ - 3y`, ...), giving `q(y)` of degree `m` whose
+/// roots `y` each yield the reciprocal pair solving `x² - yx + 1 = 0`. Returns `None`
+/// when the polynomial is not palindromic or has odd degree; an odd-degree
+/// palindromic polynomial has the root `-1`, and dividing out `x + 1` leaves an
+/// even-degree one.
+pub fn reduce_reciprocal(coefficients: &[HighPrecFloat]) -> Option<Vec<HighPrecFloat>> {
+    if !is_palindromic(coefficients) {
+        return None;
+    }
+    let degree = coefficients.iter().rposition(|&c| c != 0.0)?;
+    if degree % 2 != 0 {
+        return None;
+    }
+    let m = degree / 2;
+
+    // xᵏ + x⁻ᵏ = Pₖ(y) with P₀ = 2, P₁ = y, Pₖ = y·Pₖ₋₁ - Pₖ₋₂
+    let mut reduced = vec![0.0; m + 1];
+    reduced[0] = coefficients[m];
+    let mut previous = vec![2.0];
+    let mut current = vec![0.0, 1.0];
+    for k in 1..=m {
+        for (r, &p) in reduced.iter_mut().zip(&current) {
+            *r += coefficients[m + k] * p;
+        }
+        let mut next = vec![0.0; current.len() + 1];
+        for (i, &p) in current.iter().enumerate() {
+            next[i + 1] += p;
+        }
+        for (n, &p) in next.iter_mut().zip(&previous) {
+            *n -= p;
+        }
+        previous = std::mem::replace(&mut current, next);
+    }
+    Some(reduced)
+}
+
+/// All roots of an even-degree palindromic polynomial, via `reduce_reciprocal`
+///
+/// Solves the half-degree polynomial in `y = x + 1/x` with `solve_all_real_poly` and
+/// back-substitutes each `y` into `x² - yx + 1 = 0`, giving the reciprocal pairs
+/// `(y ± √(y² - 4)) / 2`. The roots are returned in the order of `sort_roots`, not
+/// pair by pair. Returns `None` where `reduce_reciprocal` does.
+pub fn reciprocal_roots(coefficients: &[HighPrecFloat]) -> Option<Vec<Complex<f64>>> {
+    let reduced = reduce_reciprocal(coefficients)?;
+    let mut roots = Vec::with_capacity(2 * (reduced.len() - 1));
+    for y in solve_all_real_poly(&reduced) {
+        let discriminant = (y * y - 4.0).sqrt();
+        roots.push((y + discriminant) / 2.0);
+        roots.push((y - discriminant) / 2.0);
+    }
+    sort_roots(&mut roots);
+    Some(roots)
+}
+
+/// Whether only even powers of `x` appear, so `p(x) = q(x²)` and `p(-x) = p(x)`
+///
+/// The zero polynomial is neither even nor odd here.
+pub fn is_even(coefficients: &[HighPrecFloat]) -> bool {
+    coefficients.iter().any(|&c| c != 0.0)
+        && coefficients.iter().skip(1).step_by(2).all(|&c| c == 0.0)
+}
+
+/// Whether only odd powers of `x` appear, so `p(x) = x·q(x²)` and `p(-x) = -p(x)`
+pub fn is_odd(coefficients: &[HighPrecFloat]) -> bool {
+    coefficients.iter().any(|&c| c != 0.0)
+        && coefficients.iter().step_by(2).all(|&c| c == 0.0)
+}
+
+/// Halve the degree of an even or odd polynomial with `u = x²`
+///
+/// Returns `q` with `p(x) = q(x²)` for an even polynomial or `p(x) = x·q(x²)` for an
+/// odd one, and `None` when the polynomial is neither.
+pub fn reduce_parity(coefficients: &[HighPrecFloat]) -> Option<Vec<HighPrecFloat>> {
+    let offset = if is_even(coefficients) {
+        0
+    } else if is_odd(coefficients) {
+        1
+    } else {
+        return None;
+    };
+    let len = coefficients.iter().rposition(|&c| c != 0.0).map_or(0, |i| i + 1);
+    Some(coefficients[offset..len].iter().step_by(2).copied().collect())
+}
+
+/// All roots of an even or odd polynomial, via `reduce_parity`
+///
+/// Solves `q(u) = 0` at half the degree with `solve_all_real_poly` and returns both
+/// square roots `±√u` of each root, which are imaginary for negative `u`; an odd
+/// polynomial also has the root 0. The roots are returned in the order of
+/// `sort_roots`. Returns `None` where `reduce_parity` does.
+pub fn parity_roots(coefficients: &[HighPrecFloat]) -> Option<Vec<Complex<f64>>> {
+    let reduced = reduce_parity(coefficients)?;
+    let mut roots = Vec::with_capacity(2 * reduced.len());
+    if is_odd(coefficients) {
+        roots.push(Complex::new(0.0, 0.0));
+    }
+    for u in solve_all_real_poly(&reduced) {
+        let root = u.sqrt();
+        roots.push(root);
+        roots.push(-root);
+    }
+    sort_roots(&mut roots);
+    Some(roots)
+}
+
+/// Divide out the factor `(x - root)` by synthetic division from the leading coefficient
+///
+/// Forward deflation, dropping the remainder. Rounding errors stay small relative to
+/// the remaining roots when `root` is smaller in magnitude than all of them, so divide
+/// out roots in increasing order of magnitude.
+///
+/// A constant or empty polynomial has nothing to divide and gives an empty quotient.
+pub fn deflate_forward(coefficients: &[HighPrecFloat], root: HighPrecFloat) -> Vec<HighPrecFloat> {
+    let n = coefficients.len().saturating_sub(1);
+    let mut quotient = vec![0.0; n];
+    let mut carry = 0.0;
+    for i in (0..n).rev() {
+        carry = coefficients[i + 1] + carry * root;
+        quotient[i] = carry;
+    }
+    quotient
+}
+
+/// Divide out the factor `(x - root)` by synthetic division from the constant term
+///
+/// Backward deflation, solving `c₀ = -root·q₀` and `cᵢ = qᵢ₋₁ - root·qᵢ` upwards and
+/// dropping the leading remainder; `root` must be nonzero. The mirror image of
+/// `deflate_forward`, stable when `root` is larger in magnitude than the remaining
+/// roots, so divide out roots in decreasing order of magnitude.
+///
+/// Like `deflate_forward`, a constant or empty polynomial gives an empty quotient.
+pub fn deflate_backward(coefficients: &[HighPrecFloat], root: HighPrecFloat) -> Vec<HighPrecFloat> {
+    let n = coefficients.len().saturating_sub(1);
+    let mut quotient = vec![0.0; n];
+    let mut carry = 0.0;
+    for i in 0..n {
+        carry = (carry - coefficients[i]) / root;
+        quotient[i] = carry;
+    }
+    quotient
+}
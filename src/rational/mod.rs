@@ -0,0 +1,185 @@
+use num::{BigInt, BigRational, Integer, Signed, Zero};
+
+/// All positive divisors of `n` (which must be nonzero)
+fn divisors(n: i64) -> Vec<i64> {
+    let n = n.unsigned_abs();
+    let mut result = Vec::new();
+    let mut d = 1u64;
+    while d * d <= n {
+        if n.is_multiple_of(d) {
+            result.push(d as i64);
+            if d * d != n {
+                result.push((n / d) as i64);
+            }
+        }
+        d += 1;
+    }
+    result
+}
+
+/// Exactly test whether `p/q` is a root, by evaluating `q^n * P(p/q)` over the integers
+fn is_rational_root(coefficients: &[i64], p: i64, q: i64) -> bool {
+    let degree = coefficients.len() - 1;
+    let p = BigInt::from(p);
+    let q = BigInt::from(q);
+    let mut sum = BigInt::zero();
+    for (i, &coeff) in coefficients.iter().enumerate() {
+        sum += BigInt::from(coeff) * num::pow(p.clone(), i) * num::pow(q.clone(), degree - i);
+    }
+    sum.is_zero()
+}
+
+/// Find all rational roots of an integer polynomial using the rational root theorem
+///
+/// Candidates are `±p/q` with `p` dividing the lowest nonzero coefficient and `q`
+/// dividing the leading coefficient; each is tested by exact integer evaluation.
+/// Roots are returned as reduced fractions `(numerator, denominator)` with a positive
+/// denominator, in increasing order. A zero root is reported as `(0, 1)`.
+pub fn rational_roots(coefficients: &[i64]) -> Vec<(i64, i64)> {
+    // Drop zero leading coefficients
+    let len = coefficients.iter().rposition(|&c| c != 0).map_or(0, |i| i + 1);
+    let coefficients = &coefficients[..len];
+    if coefficients.len() < 2 {
+        return Vec::new();
+    }
+
+    // Factor out x^k for zero low-order coefficients
+    let low = coefficients.iter().position(|&c| c != 0).unwrap_or(0);
+    let reduced = &coefficients[low..];
+
+    let mut roots = Vec::new();
+    if low > 0 {
+        roots.push((0, 1));
+    }
+
+    if reduced.len() >= 2 {
+        let leading = reduced[reduced.len() - 1];
+        for p in divisors(reduced[0]) {
+            for q in divisors(leading) {
+                if p.gcd(&q) != 1 {
+                    continue;
+                }
+                for candidate in [p, -p] {
+                    if is_rational_root(reduced, candidate, q) {
+                        roots.push((candidate, q));
+                    }
+                }
+            }
+        }
+    }
+
+    roots.sort_by(|a, b| (a.0 as i128 * b.1 as i128).cmp(&(b.0 as i128 * a.1 as i128)));
+    roots
+}
+
+/// Derivative of a polynomial with rational coefficients, constant term first
+fn derivative(p: &[BigRational]) -> Vec<BigRational> {
+    p.iter()
+        .enumerate()
+        .skip(1)
+        .map(|(i, c)| c * BigRational::from_integer(BigInt::from(i)))
+        .collect()
+}
+
+/// Greatest common divisor by Euclid's algorithm, up to a constant factor; both inputs must be trimmed
+fn polynomial_gcd(a: &[BigRational], b: &[BigRational]) -> Vec<BigRational> {
+    let (mut a, mut b) = (a.to_vec(), b.to_vec());
+    while !b.is_empty() {
+        let remainder = polynomial_remainder(&a, &b);
+        a = std::mem::replace(&mut b, remainder);
+    }
+    a
+}
+
+/// Quotient of `dividend / divisor` by exact long division; `divisor` must be trimmed
+fn polynomial_quotient(dividend: &[BigRational], divisor: &[BigRational]) -> Vec<BigRational> {
+    if dividend.len() < divisor.len() {
+        return Vec::new();
+    }
+    let mut remainder = dividend.to_vec();
+    let leading = &divisor[divisor.len() - 1];
+    let mut quotient = vec![BigRational::zero(); dividend.len() - divisor.len() + 1];
+    for shift in (0..quotient.len()).rev() {
+        let factor = &remainder[shift + divisor.len() - 1] / leading;
+        for (i, d) in divisor.iter().enumerate() {
+            remainder[shift + i] -= &factor * d;
+        }
+        quotient[shift] = factor;
+    }
+    quotient
+}
+
+/// Remainder of `dividend / divisor` by exact long division; `divisor` must be trimmed
+fn polynomial_remainder(dividend: &[BigRational], divisor: &[BigRational]) -> Vec<BigRational> {
+    let mut remainder = dividend.to_vec();
+    let leading = &divisor[divisor.len() - 1];
+    while remainder.len() >= divisor.len() {
+        let shift = remainder.len() - divisor.len();
+        let factor = &remainder[remainder.len() - 1] / leading;
+        for (i, d) in divisor.iter().enumerate() {
+            remainder[shift + i] -= &factor * d;
+        }
+        remainder.pop();
+        while remainder.last().is_some_and(|c| c.is_zero()) {
+            remainder.pop();
+        }
+    }
+    remainder
+}
+
+/// Number of sign changes along the Sturm chain at `x`, skipping zeros
+fn sign_changes(chain: &[Vec<BigRational>], x: &BigRational) -> usize {
+    let mut changes = 0;
+    let mut previous: Option<bool> = None;
+    for polynomial in chain {
+        let value = polynomial.iter()
+            .rev()
+            .fold(BigRational::zero(), |acc, c| acc * x + c);
+        if value.is_zero() {
+            continue;
+        }
+        let positive = value.is_positive();
+        if previous.is_some_and(|p| p != positive) {
+            changes += 1;
+        }
+        previous = Some(positive);
+    }
+    changes
+}
+
+/// Exactly count the distinct real roots of an integer polynomial in `(a, b]`
+///
+/// Divides out `gcd(p, p')` to get the square-free part `q`, which has the same
+/// roots without their multiplicities, then builds the Sturm chain
+/// `q, q', -rem(q, q'), ...`, all in `BigRational`. Unlike a floating-point chain the
+/// count is exact even near tightly clustered roots, and since `q` has only simple
+/// roots the chain never vanishes entirely at an endpoint that is a multiple root.
+/// Multiple roots are counted once. Returns 0 when `a >= b` or the polynomial is
+/// constant.
+pub fn count_real_roots_exact(coefficients: &[i64], a: i64, b: i64) -> usize {
+    let len = coefficients.iter().rposition(|&c| c != 0).map_or(0, |i| i + 1);
+    if len < 2 || a >= b {
+        return 0;
+    }
+
+    let p: Vec<BigRational> = coefficients[..len].iter()
+        .map(|&c| BigRational::from_integer(BigInt::from(c)))
+        .collect();
+    let gcd = polynomial_gcd(&p, &derivative(&p));
+    let square_free = polynomial_quotient(&p, &gcd);
+
+    let square_free_derivative = derivative(&square_free);
+    let mut chain = vec![square_free, square_free_derivative];
+    loop {
+        let n = chain.len();
+        let remainder = polynomial_remainder(&chain[n - 2], &chain[n - 1]);
+        if remainder.is_empty() {
+            break;
+        }
+        chain.push(remainder.into_iter().map(|c| -c).collect());
+    }
+
+    let a = BigRational::from_integer(BigInt::from(a));
+    let b = BigRational::from_integer(BigInt::from(b));
+    sign_changes(&chain, &a) - sign_changes(&chain, &b)
+}
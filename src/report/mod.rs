@@ -0,0 +1,106 @@
+use num::BigRational;
+
+use crate::{evaluate_derivative, evaluate_polynomial};
+use crate::solver::HighPrecFloat;
+use crate::subdigon::SubdigonType;
+
+/// Diagnostics accompanying a computed root
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolveReport {
+    /// The computed root
+    pub root: HighPrecFloat,
+    /// Absolute residual |p(root)|
+    pub residual: HighPrecFloat,
+    /// Residual relative to the size of the terms, `|p(root)| / Σ|cᵢ||root|ⁱ`
+    ///
+    /// A dimensionless backward error between 0 and 1 that, unlike `residual`, does
+    /// not change when the polynomial is scaled, so one threshold (say `1e-12`) can
+    /// judge roots of any polynomial.
+    pub normalized_residual: HighPrecFloat,
+    /// Relative condition number of the root, `Σ|cᵢ||r|ⁱ / (|r|·|p'(r)|)`
+    ///
+    /// A relative perturbation `δ` in the coefficients moves the root by roughly
+    /// `condition_number · δ` relative to its size, so values far above 1 (as for the
+    /// interior roots of Wilkinson's polynomial) mean the root is inherently unstable.
+    /// Infinite at multiple roots.
+    pub condition_number: HighPrecFloat,
+    /// Estimated distance to the true root, `|p(root)| / |p'(root)|`; see `root_float_error_bound`
+    pub accuracy_bound: HighPrecFloat,
+    /// Whether the root passed the solver's residual check
+    ///
+    /// Always `true` except for best-effort estimates; see
+    /// `HyperCatalanPolynomialSolver::set_best_effort`.
+    pub converged: bool,
+    /// Subdigon type whose series term was largest in absolute value
+    ///
+    /// Only set by `solve_polynomial_report` when the series was summed; shows whether
+    /// the sum is driven by small or many-faced subdigons.
+    pub dominant_type: Option<SubdigonType>,
+    /// Exact value of the truncated geometric-form series, when solved from rationals
+    ///
+    /// Only set by `solve_polynomial_exact`; the root itself is still rounded to `f64`.
+    pub series_value_exact: Option<BigRational>,
+}
+
+impl SolveReport {
+    /// Compute the diagnostics for `root` as a root of the given polynomial
+    pub fn for_root(coefficients: &[HighPrecFloat], root: HighPrecFloat) -> Self {
+        SolveReport {
+            root,
+            residual: evaluate_polynomial(coefficients, root).abs(),
+            normalized_residual: normalized_residual(coefficients, root),
+            condition_number: root_condition_number(coefficients, root),
+            accuracy_bound: root_float_error_bound(coefficients, root),
+            converged: true,
+            dominant_type: None,
+            series_value_exact: None,
+        }
+    }
+}
+
+/// Residual of `root` relative to the size of the terms, `|p(root)| / Σ|cᵢ||root|ⁱ`
+///
+/// Zero when every term vanishes (the zero polynomial, or a zero root of a
+/// polynomial without a constant term).
+pub fn normalized_residual(coefficients: &[HighPrecFloat], root: HighPrecFloat) -> HighPrecFloat {
+    let magnitude: HighPrecFloat = coefficients.iter()
+        .enumerate()
+        .map(|(i, c)| c.abs() * root.abs().powi(i as i32))
+        .sum();
+    if magnitude == 0.0 {
+        return 0.0;
+    }
+    evaluate_polynomial(coefficients, root).abs() / magnitude
+}
+
+/// Estimate how far `root` is from the exact root nearby, `|p(root)| / |p'(root)|`
+///
+/// This is the length of the Newton step from `root`, turning the backward error
+/// (the residual) into a forward error in `x`. For a simple, well-separated root it
+/// bounds the error to first order, so it is zero when the root is exact in `f64`.
+/// Infinite where `p'(root) = 0` but `p(root) ≠ 0`.
+pub fn root_float_error_bound(coefficients: &[HighPrecFloat], root: HighPrecFloat) -> HighPrecFloat {
+    let residual = evaluate_polynomial(coefficients, root).abs();
+    if residual == 0.0 {
+        return 0.0;
+    }
+    residual / evaluate_derivative(coefficients, root).abs()
+}
+
+/// Relative condition number of a simple root with respect to coefficient perturbations
+pub fn root_condition_number(coefficients: &[HighPrecFloat], root: HighPrecFloat) -> HighPrecFloat {
+    let mut magnitude = 0.0;
+    let mut derivative = 0.0;
+    for (i, &coeff) in coefficients.iter().enumerate() {
+        magnitude += coeff.abs() * root.abs().powi(i as i32);
+        if i > 0 {
+            derivative += (i as HighPrecFloat) * coeff * root.powi(i as i32 - 1);
+        }
+    }
+
+    if root == 0.0 {
+        // Relative perturbation of a zero root is measured against 1
+        return magnitude / derivative.abs();
+    }
+    magnitude / (root.abs() * derivative.abs())
+}
@@ -0,0 +1,43 @@
+/// Source of pseudo-random bits for the stochastic methods
+///
+/// Methods that draw random numbers accept any implementation through their `_with_rng`
+/// variants, so callers can plug in another generator (for instance one from the
+/// `rand` crate); the seeded variants use `SplitMix64`. Implementations should be
+/// deterministic for a given seed to keep results reproducible.
+pub trait RandomSource {
+    /// Next 64 pseudo-random bits
+    fn next_u64(&mut self) -> u64;
+
+    /// Uniform sample from `[low, high)`
+    fn next_f64(&mut self, low: f64, high: f64) -> f64 {
+        // 53 random bits give every representable multiple of 2^-53 in [0, 1)
+        let unit = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        low + (high - low) * unit
+    }
+}
+
+/// Small deterministic pseudo-random generator (SplitMix64)
+///
+/// Stochastic methods take a `u64` seed and draw from this generator, so results are
+/// reproducible across runs and platforms without any thread-local state.
+#[derive(Debug, Clone)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    /// Create a generator from a seed
+    pub fn new(seed: u64) -> Self {
+        SplitMix64 { state: seed }
+    }
+}
+
+impl RandomSource for SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
@@ -0,0 +1,300 @@
+use num::Complex;
+
+#[cfg(feature = "nalgebra")]
+use nalgebra::DMatrix;
+
+use crate::polynomial::{balance_coefficients, cauchy_bound};
+use crate::evaluate_polynomial_complex;
+use crate::solver::HighPrecFloat;
+
+/// Maximum Laguerre iterations per root before giving up on further improvement
+const MAX_LAGUERRE_ITERATIONS: usize = 200;
+
+/// Relative distance within which roots are treated as real, or as one multiple root
+///
+/// A root of multiplicity `m` is only found to about `ε^(1/m)` in double precision
+/// (`1e-8` for double roots, `6e-6` for triple ones), split into nearby real or
+/// complex copies, so this has to be much looser than the precision of simple roots.
+pub const ROOT_CLUSTER_TOLERANCE: HighPrecFloat = 1e-5;
+
+/// All roots of a polynomial, split into distinct real roots and complex roots
+#[derive(Debug, Clone, PartialEq)]
+pub struct RootSummary {
+    /// Distinct real roots in increasing order
+    pub real: Vec<HighPrecFloat>,
+    /// Roots with a nonzero imaginary part, conjugate pairs included, in the order of `sort_roots`
+    pub complex: Vec<Complex<f64>>,
+    /// Multiplicity of each real root, in the same order as `real`
+    pub multiplicities: Vec<usize>,
+}
+
+/// Sort roots by magnitude, then by real part, then by imaginary part
+///
+/// Gives every multi-root result a deterministic order independent of how the roots
+/// were found; a conjugate pair comes out with the negative imaginary part first.
+pub fn sort_roots(roots: &mut [Complex<f64>]) {
+    roots.sort_by(|a, b| {
+        a.norm()
+            .total_cmp(&b.norm())
+            .then(a.re.total_cmp(&b.re))
+            .then(a.im.total_cmp(&b.im))
+    });
+}
+
+/// Evaluate a complex polynomial with its first and second derivatives (Horner)
+fn eval_with_derivatives(
+    coefficients: &[Complex<f64>],
+    x: Complex<f64>,
+) -> (Complex<f64>, Complex<f64>, Complex<f64>) {
+    let zero = Complex::new(0.0, 0.0);
+    let (mut p, mut dp, mut ddp) = (zero, zero, zero);
+    for &coeff in coefficients.iter().rev() {
+        ddp = ddp * x + dp * 2.0;
+        dp = dp * x + p;
+        p = p * x + coeff;
+    }
+    (p, dp, ddp)
+}
+
+/// Find one root of a complex polynomial with Laguerre's method, starting from `x`
+///
+/// Laguerre's method converges cubically to simple roots from almost any starting
+/// point. Every tenth step is shortened by a varying fraction to break the rare
+/// limit cycles, as suggested in Numerical Recipes.
+fn laguerre(coefficients: &[Complex<f64>], mut x: Complex<f64>) -> Complex<f64> {
+    let n = (coefficients.len() - 1) as f64;
+    let fractions = [0.5, 0.25, 0.75, 0.13, 0.38, 0.62, 0.88, 1.0];
+
+    for iteration in 1..=MAX_LAGUERRE_ITERATIONS {
+        let (p, dp, ddp) = eval_with_derivatives(coefficients, x);
+        if p.norm() == 0.0 {
+            break;
+        }
+
+        let g = dp / p;
+        let h = g * g - ddp / p;
+        let root = ((h * n - g * g) * (n - 1.0)).sqrt();
+        let plus = g + root;
+        let minus = g - root;
+        let denominator = if plus.norm() >= minus.norm() { plus } else { minus };
+
+        let step = if denominator.norm() > 0.0 {
+            Complex::new(n, 0.0) / denominator
+        } else {
+            Complex::from_polar(1.0 + x.norm(), iteration as f64)
+        };
+
+        let next = if iteration % 10 == 0 {
+            x - step * fractions[(iteration / 10) % fractions.len()]
+        } else {
+            x - step
+        };
+
+        if next == x || step.norm() <= f64::EPSILON * x.norm() {
+            return next;
+        }
+        x = next;
+    }
+    x
+}
+
+/// Divide out the factor `(x - root)` by synthetic division, dropping the remainder
+fn deflate(coefficients: &[Complex<f64>], root: Complex<f64>) -> Vec<Complex<f64>> {
+    let n = coefficients.len() - 1;
+    let mut quotient = vec![Complex::new(0.0, 0.0); n];
+    let mut carry = coefficients[n];
+    for i in (0..n).rev() {
+        quotient[i] = carry;
+        carry = coefficients[i] + carry * root;
+    }
+    quotient
+}
+
+/// Polish a root estimate against the full polynomial with a few Newton steps
+fn polish(coefficients: &[Complex<f64>], mut x: Complex<f64>) -> Complex<f64> {
+    for _ in 0..10 {
+        let (p, dp, _) = eval_with_derivatives(coefficients, x);
+        if dp.norm() == 0.0 {
+            break;
+        }
+        let step = p / dp;
+        let next = x - step;
+        // Stop once a step no longer reduces the residual
+        if eval_with_derivatives(coefficients, next).0.norm() >= p.norm() {
+            break;
+        }
+        x = next;
+    }
+    x
+}
+
+/// Find all complex roots of a polynomial with real coefficients
+///
+/// The polynomial is first balanced with `balance_coefficients`, which matters for
+/// ill-conditioned inputs such as Wilkinson's polynomial. Roots are then found one at
+/// a time by Laguerre's method with deflation, and each is polished with Newton's
+/// method on the undeflated polynomial so deflation errors do not accumulate. Roots
+/// are returned in the order of `sort_roots`.
+pub fn solve_all_real_poly(coefficients: &[HighPrecFloat]) -> Vec<Complex<f64>> {
+    let len = coefficients.iter().rposition(|&c| c != 0.0).map_or(0, |i| i + 1);
+    if len < 2 {
+        return Vec::new();
+    }
+
+    let (balanced, scale) = balance_coefficients(&coefficients[..len]);
+    let full: Vec<Complex<f64>> = balanced.iter().map(|&c| Complex::new(c, 0.0)).collect();
+
+    let mut remaining = full.clone();
+    let mut roots = Vec::with_capacity(len - 1);
+    while remaining.len() > 1 {
+        let estimate = laguerre(&remaining, Complex::new(0.0, 0.0));
+        let root = polish(&full, estimate);
+        roots.push(root * scale);
+        remaining = deflate(&remaining, estimate);
+    }
+    sort_roots(&mut roots);
+    roots
+}
+
+/// Find all complex roots at once with the Durand–Kerner (Weierstrass) iteration
+///
+/// Every estimate is updated simultaneously with `zᵢ ← zᵢ - p(zᵢ) / Πⱼ≠ᵢ(zᵢ - zⱼ)` on the
+/// monic polynomial, starting from powers of `0.4 + 0.9i` scaled to the Cauchy bound,
+/// until no estimate moves by more than `tolerance` (relative to its size) or
+/// `max_iterations` sweeps have run. Converges for almost all inputs, if only
+/// linearly at multiple roots, and needs no deflation. Roots are returned in the
+/// order of `sort_roots`.
+pub fn durand_kerner(coefficients: &[HighPrecFloat], max_iterations: usize, tolerance: HighPrecFloat) -> Vec<Complex<f64>> {
+    let len = coefficients.iter().rposition(|&c| c != 0.0).map_or(0, |i| i + 1);
+    if len < 2 {
+        return Vec::new();
+    }
+    let leading = coefficients[len - 1];
+    let monic: Vec<Complex<f64>> = coefficients[..len].iter().map(|&c| Complex::new(c / leading, 0.0)).collect();
+
+    let radius = cauchy_bound(&coefficients[..len]);
+    let seed = Complex::new(0.4, 0.9);
+    let mut roots: Vec<Complex<f64>> = (0..len - 1).map(|k| seed.powu(k as u32) * radius).collect();
+
+    for _ in 0..max_iterations {
+        let mut largest_step: HighPrecFloat = 0.0;
+        for i in 0..roots.len() {
+            let denominator: Complex<f64> = roots.iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(_, &other)| roots[i] - other)
+                .product();
+            if denominator.norm() == 0.0 {
+                continue;
+            }
+            let step = evaluate_polynomial_complex(&monic, roots[i]) / denominator;
+            roots[i] -= step;
+            largest_step = largest_step.max(step.norm() / (1.0 + roots[i].norm()));
+        }
+        if largest_step <= tolerance {
+            break;
+        }
+    }
+    sort_roots(&mut roots);
+    roots
+}
+
+/// Find all complex roots as the eigenvalues of the companion matrix
+///
+/// The companion matrix of the monic polynomial has ones on the subdiagonal and
+/// `-cᵢ/cₙ` in its last column, and its characteristic polynomial is `p`. The
+/// eigenvalues come from nalgebra's Schur decomposition, which is backward stable, so
+/// this is a robust reference for the iterative methods at `O(n³)` cost. Roots are
+/// returned in the order of `sort_roots`.
+#[cfg(feature = "nalgebra")]
+pub fn companion_roots(coefficients: &[HighPrecFloat]) -> Vec<Complex<f64>> {
+    let len = coefficients.iter().rposition(|&c| c != 0.0).map_or(0, |i| i + 1);
+    if len < 2 {
+        return Vec::new();
+    }
+    let n = len - 1;
+    let leading = coefficients[n];
+    let companion = DMatrix::from_fn(n, n, |i, j| {
+        if j == n - 1 {
+            -coefficients[i] / leading
+        } else if i == j + 1 {
+            1.0
+        } else {
+            0.0
+        }
+    });
+    let mut roots: Vec<Complex<f64>> = companion.complex_eigenvalues().iter().copied().collect();
+    sort_roots(&mut roots);
+    roots
+}
+
+/// Sort roots and collapse each run of roots within `tol` of its neighbour into one
+///
+/// Deflation and Newton's linear convergence at multiple roots both leave near
+/// copies of the same root; each cluster is replaced by its mean.
+pub fn dedup_roots(roots: &mut Vec<HighPrecFloat>, tol: HighPrecFloat) {
+    roots.sort_by(|a, b| a.total_cmp(b));
+    let mut merged: Vec<HighPrecFloat> = Vec::with_capacity(roots.len());
+    let mut cluster_sum = 0.0;
+    let mut cluster_len = 0;
+    for (i, &root) in roots.iter().enumerate() {
+        if i > 0 && root - roots[i - 1] > tol {
+            merged.push(cluster_sum / cluster_len as HighPrecFloat);
+            cluster_sum = 0.0;
+            cluster_len = 0;
+        }
+        cluster_sum += root;
+        cluster_len += 1;
+    }
+    if cluster_len > 0 {
+        merged.push(cluster_sum / cluster_len as HighPrecFloat);
+    }
+    *roots = merged;
+}
+
+/// Number of distinct roots once those within `tol` of each other are merged
+pub fn count_distinct_roots(roots: &[HighPrecFloat], tol: HighPrecFloat) -> usize {
+    let mut roots = roots.to_vec();
+    dedup_roots(&mut roots, tol);
+    roots.len()
+}
+
+/// Find every root with `solve_all_real_poly` and classify it as real or complex
+///
+/// A root counts as real when its imaginary part is within `ROOT_CLUSTER_TOLERANCE`
+/// of its size (`|im| <= tol·(1 + |z|)`). Real roots are then sorted and runs within
+/// the same relative tolerance of each other are merged into one root, the mean of
+/// the run, whose multiplicity is the run's length.
+pub fn classify_roots(coefficients: &[HighPrecFloat]) -> RootSummary {
+    let mut real_parts = Vec::new();
+    let mut complex = Vec::new();
+    for root in solve_all_real_poly(coefficients) {
+        if root.im.abs() <= ROOT_CLUSTER_TOLERANCE * (1.0 + root.norm()) {
+            real_parts.push(root.re);
+        } else {
+            complex.push(root);
+        }
+    }
+    real_parts.sort_by(|a, b| a.total_cmp(b));
+    sort_roots(&mut complex);
+
+    let mut real = Vec::new();
+    let mut multiplicities = Vec::new();
+    let mut cluster: Vec<HighPrecFloat> = Vec::new();
+    for &x in &real_parts {
+        if let Some(&last) = cluster.last() {
+            if x - last > ROOT_CLUSTER_TOLERANCE * (1.0 + x.abs()) {
+                real.push(cluster.iter().sum::<HighPrecFloat>() / cluster.len() as HighPrecFloat);
+                multiplicities.push(cluster.len());
+                cluster.clear();
+            }
+        }
+        cluster.push(x);
+    }
+    if !cluster.is_empty() {
+        real.push(cluster.iter().sum::<HighPrecFloat>() / cluster.len() as HighPrecFloat);
+        multiplicities.push(cluster.len());
+    }
+
+    RootSummary { real, complex, multiplicities }
+}
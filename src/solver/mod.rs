@@ -1,113 +1,411 @@
-use num::ToPrimitive;
+use std::collections::HashMap;
 
+use num::{BigInt, BigRational, Complex, One, Signed, ToPrimitive, Zero};
+
+use crate::acceleration::Acceleration;
 use crate::calculator::HyperCatalanCalculator;
-use crate::subdigon::SubdigonType;
+use crate::dual::eval_dual;
+use crate::error::SolverError;
+use crate::{evaluate_polynomial, evaluate_polynomial_complex};
+use crate::polynomial::{
+    balance_coefficients, cauchy_bound, deflate_backward, deflate_forward, depress, format_polynomial, parity_roots,
+    reciprocal_roots, Polynomial,
+};
+use crate::rational::rational_roots;
+use crate::report::SolveReport;
+#[cfg(feature = "nalgebra")]
+use crate::roots::companion_roots;
+use crate::roots::{classify_roots, dedup_roots, durand_kerner, solve_all_real_poly, sort_roots, RootSummary};
+use crate::rng::{RandomSource, SplitMix64};
+use crate::subdigon::{SubdigonType, SubdigonTypes};
 
 /// High precision floating point type alias
 pub type HighPrecFloat = f64;
 
+/// Method that produced the roots returned by `solve_robust`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RobustPath {
+    /// The series converged; its root was polished and the rest found after deflating it
+    Series,
+    /// The series failed, so every root came from the Durand–Kerner iteration
+    DurandKerner,
+    /// The polynomial was palindromic, so `reciprocal_roots` solved it at half the degree
+    Reciprocal,
+    /// The polynomial was even or odd, so `parity_roots` solved it in `x²`
+    Parity,
+}
+
+/// Order in which `solve_all_roots` finds and divides out roots
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DeflationOrder {
+    /// Smallest roots first, each divided out with `deflate_forward` (the default)
+    #[default]
+    SmallestFirst,
+    /// Largest roots first, each divided out with `deflate_backward`
+    LargestFirst,
+}
+
+/// Sweep limit and step tolerance for the Durand–Kerner fallback and method
+const DURAND_KERNER_ITERATIONS: usize = 500;
+const DURAND_KERNER_TOLERANCE: HighPrecFloat = 1e-14;
+
+/// Algorithm used by `solve_all` to find every root
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Method {
+    /// Series-seeded Newton's method with deflation (`solve_all_roots`); real roots only
+    #[default]
+    SeriesNewton,
+    /// Eigenvalues of the companion matrix (`companion_roots`)
+    #[cfg(feature = "nalgebra")]
+    CompanionMatrix,
+    /// Simultaneous Durand–Kerner iteration (`durand_kerner`)
+    DurandKerner,
+    /// Laguerre's method with deflation and polishing (`solve_all_real_poly`)
+    Laguerre,
+}
+
+/// Default largest relative residual `|p(x)| / Σ|cᵢ||x|ⁱ` accepted from the series
+pub const DEFAULT_RESIDUAL_TOLERANCE: HighPrecFloat = 1e-3;
+
+/// Snapshot of the series summation after one face level
+#[derive(Debug, Clone, Copy)]
+struct SeriesLevel {
+    levels_completed: usize,
+    terms_used: usize,
+    partial_sum: HighPrecFloat,
+    /// Root estimate in the variable of the polynomial being solved at this layer
+    root_estimate: HighPrecFloat,
+}
+
+/// Memoization key for a series sum: `(max_terms, polygon sizes, quantized t₂, t₃, ...)`
+type SeriesKey = (usize, usize, Vec<i64>);
+
 /// Solver for polynomial equations using the Hyper-Catalan series
+///
+/// Cloning a solver copies its cache of Hyper-Catalan numbers, so a warmed-up
+/// solver can be cloned once per worker thread.
+#[derive(Clone)]
 pub struct HyperCatalanPolynomialSolver {
     max_degree: usize,
     max_terms: usize,
     calculator: HyperCatalanCalculator,
     debug_mode: bool,
+    balance: bool,
+    depress: bool,
+    acceleration: Acceleration,
+    residual_tolerance: Option<HighPrecFloat>,
+    relative_tolerance: bool,
+    max_terms_evaluated: Option<usize>,
+    max_polygon_size: Option<usize>,
+    series_quantum: Option<HighPrecFloat>,
+    series_memo: HashMap<SeriesKey, (Vec<SeriesLevel>, Option<SubdigonType>)>,
+    dominant_type: Option<SubdigonType>,
+    series_memo_hits: usize,
+    best_effort: bool,
+    rational_shortcut: bool,
+    deflation_order: DeflationOrder,
+    method: Method,
+}
+
+impl Default for HyperCatalanPolynomialSolver {
+    /// A solver for polynomials up to degree 5 using 20 face levels of the series
+    fn default() -> Self {
+        HyperCatalanPolynomialSolver::new(5, 20)
+    }
 }
 
 impl HyperCatalanPolynomialSolver {
     /// Create a new solver with the given maximum degree and terms
+    ///
+    /// `max_terms` is the number of face levels summed and must be at least 1;
+    /// with zero terms `solve_polynomial` returns `SolverError::NoSeriesTerms`.
     pub fn new(max_degree: usize, max_terms: usize) -> Self {
         HyperCatalanPolynomialSolver {
             max_degree,
             max_terms,
             calculator: HyperCatalanCalculator::new(),
             debug_mode: false,
+            balance: false,
+            depress: false,
+            acceleration: Acceleration::None,
+            residual_tolerance: Some(DEFAULT_RESIDUAL_TOLERANCE),
+            relative_tolerance: false,
+            max_terms_evaluated: None,
+            max_polygon_size: None,
+            series_quantum: None,
+            series_memo: HashMap::new(),
+            dominant_type: None,
+            series_memo_hits: 0,
+            best_effort: false,
+            rational_shortcut: false,
+            deflation_order: DeflationOrder::SmallestFirst,
+            method: Method::SeriesNewton,
         }
     }
 
+    /// Create a new solver whose maximum degree matches the given polynomial
+    ///
+    /// High-order zero coefficients are ignored when determining the degree.
+    pub fn for_coefficients(coefficients: &[HighPrecFloat], max_terms: usize) -> Self {
+        let degree = coefficients.iter().rposition(|&c| c != 0.0).unwrap_or(0);
+        HyperCatalanPolynomialSolver::new(degree.max(1), max_terms)
+    }
+
     /// Create a new solver with debug mode enabled
     pub fn new_with_debug(max_degree: usize, max_terms: usize) -> Self {
         HyperCatalanPolynomialSolver {
-            max_degree,
-            max_terms,
-            calculator: HyperCatalanCalculator::new(),
             debug_mode: true,
+            ..Self::new(max_degree, max_terms)
         }
     }
 
+    /// Access the underlying Hyper-Catalan calculator and its cache
+    pub fn calculator(&self) -> &HyperCatalanCalculator {
+        &self.calculator
+    }
+
+    /// Clear cached state while keeping the configuration
+    ///
+    /// Frees the calculator's cache and the memoized series sums, and zeroes the memo
+    /// hit count, so a long-lived solver does not keep growing across unrelated
+    /// problems. Cached Hyper-Catalan numbers depend only on the subdigon type, never
+    /// on the polynomial, so skip this to keep them warm.
+    pub fn reset(&mut self) {
+        self.calculator.clear_cache();
+        self.series_memo.clear();
+        self.series_memo_hits = 0;
+    }
+
     /// Set debug mode
     pub fn set_debug_mode(&mut self, debug: bool) {
         self.debug_mode = debug;
     }
 
-    /// Generate all possible subdigon types with the given total faces and maximum polygon size
-    fn generate_types(&self, total_faces: usize, max_polygon_size: usize) -> Vec<Vec<i32>> {
-        let mut results = Vec::new();
-        let mut current = vec![0; max_polygon_size];
-        self.generate_types_recursive(&mut results, &mut current, total_faces, 0, max_polygon_size);
-        results
+    /// Enable or disable coefficient balancing before solving
+    ///
+    /// When enabled, `solve_polynomial` rescales the polynomial with
+    /// `balance_coefficients` and maps the root back to the original variable.
+    pub fn set_balancing(&mut self, balance: bool) {
+        self.balance = balance;
     }
 
-    /// Recursive helper for generating subdigon types
-    fn generate_types_recursive(
-        &self,
-        results: &mut Vec<Vec<i32>>,
-        current: &mut Vec<i32>,
-        remaining_faces: usize,
-        index: usize,
-        max_polygon_size: usize,
-    ) {
-        // Base case: reached the end of our vector
-        if index == max_polygon_size {
-            if remaining_faces == 0 {
-                results.push(current.clone());
-            }
-            return;
-        }
-
-        // Try each possible count for the current polygon size
-        for i in 0..=remaining_faces {
-            current[index] = i as i32;
-            self.generate_types_recursive(
-                results,
-                current,
-                remaining_faces - i,
-                index + 1,
-                max_polygon_size,
+    /// Enable or disable depressing the polynomial before solving
+    ///
+    /// When enabled, `solve_polynomial` removes the `x^{n-1}` term with `depress`,
+    /// solves the result with `solve_depressed` and shifts the root back.
+    pub fn set_depressing(&mut self, depress: bool) {
+        self.depress = depress;
+    }
+
+    /// Choose how the series partial sums are accelerated before mapping to a root
+    ///
+    /// The strategy is applied to the partial sums after each face level, trading
+    /// series terms for accuracy. The default `Acceleration::None` uses the final
+    /// partial sum; if a strategy yields a non-finite value the final partial sum is
+    /// used instead. Progress callbacks still report the raw partial sums.
+    pub fn set_acceleration(&mut self, acceleration: Acceleration) {
+        self.acceleration = acceleration;
+    }
+
+    /// Set the largest relative residual accepted from `solve_polynomial`, or `None` to accept any root
+    ///
+    /// The series root is checked against the original polynomial: if
+    /// `|p(x)| / Σ|cᵢ||x|ⁱ` exceeds the tolerance (as when the series is summed outside
+    /// its radius of convergence), `SolverError::NotConverged` is returned with the
+    /// absolute residual `|p(x)|` instead of a silently wrong root. The ratio is at
+    /// most 1, and defaults to `DEFAULT_RESIDUAL_TOLERANCE`.
+    pub fn set_residual_tolerance(&mut self, tolerance: Option<HighPrecFloat>) {
+        self.residual_tolerance = tolerance;
+    }
+
+    /// Use a mixed absolute/relative step criterion in Newton's method
+    ///
+    /// By default Newton's method stops once `|delta| < epsilon`, which cannot be met
+    /// for large roots whose spacing between floats exceeds `epsilon`. When enabled
+    /// it stops once `|delta| < epsilon · (1 + |x|)`: absolute near zero, relative for
+    /// large `|x|`. The `|f(x)| < epsilon` test is unchanged.
+    pub fn set_relative_tolerance(&mut self, relative: bool) {
+        self.relative_tolerance = relative;
+    }
+
+    /// Return the best root estimate instead of `SolverError::NotConverged`
+    ///
+    /// When enabled and the series root fails the residual check (or the work budget
+    /// runs out), `solve_polynomial` returns whichever partial-sum root estimate has
+    /// the smallest residual, and `solve_polynomial_report` marks it with
+    /// `converged: false`. Useful interactively, where a rough seed beats no answer.
+    /// Off by default.
+    pub fn set_best_effort(&mut self, best_effort: bool) {
+        self.best_effort = best_effort;
+    }
+
+    /// Check integer polynomials for an exact rational root before summing the series
+    ///
+    /// When enabled, `solve_polynomial` first runs `rational_roots` on coefficients
+    /// that are integers up to `1e6` in size, once the degree and term limits have
+    /// been validated, and returns the rational root of smallest magnitude (the root
+    /// the series converges to when it converges) without summing the series. The
+    /// candidates grow with the number of divisors of the end coefficients, and the
+    /// series settings (polygon size, work budget) do not apply to this check, so it
+    /// is off by default.
+    pub fn set_rational_shortcut(&mut self, enabled: bool) {
+        self.rational_shortcut = enabled;
+    }
+
+    /// Choose whether `solve_all_roots` works from the smallest or the largest root
+    ///
+    /// Dividing out an approximate root perturbs the remaining coefficients, and the
+    /// perturbation stays small relative to the remaining roots only when the
+    /// synthetic division runs in the right direction: forward (from the leading
+    /// coefficient) for roots smaller than the rest, backward (from the constant term)
+    /// for larger ones. Both orders pair the seed with the matching direction; the
+    /// wrong pairing can lose half the digits of small roots next to large ones.
+    pub fn set_deflation_order(&mut self, order: DeflationOrder) {
+        self.deflation_order = order;
+    }
+
+    /// Choose the algorithm `solve_all` uses, `Method::SeriesNewton` by default
+    pub fn set_method(&mut self, method: Method) {
+        self.method = method;
+    }
+
+    /// Cap the number of series terms evaluated per solve
+    ///
+    /// The number of subdigon types grows combinatorially with the degree and
+    /// `max_terms`, so a large polynomial can take a very long time to sum. Once more
+    /// than `cap` terms have been evaluated the solve aborts with
+    /// `SolverError::NotConverged`.
+    pub fn set_max_terms_evaluated(&mut self, cap: usize) {
+        self.max_terms_evaluated = Some(cap);
+    }
+
+    /// Override the number of polygon sizes enumerated in the series, or `None` for the default
+    ///
+    /// By default the series sums over the `max_degree - 1` polygon sizes matching the
+    /// coefficients `t₂, ..., t_{max_degree}`. Extending it only adds terms with a zero
+    /// coefficient; shrinking it below the default drops the high-order terms of the
+    /// polynomial, so the series then solves a truncated equation (a warning is printed
+    /// by each such solve in debug mode).
+    pub fn set_max_polygon_size(&mut self, size: Option<usize>) {
+        self.max_polygon_size = size;
+    }
+
+    /// Number of polygon sizes enumerated in the series, `max_degree - 1` unless overridden
+    pub fn max_polygon_size(&self) -> usize {
+        self.max_polygon_size.unwrap_or(self.max_degree.saturating_sub(1))
+    }
+
+    /// Memoize series sums keyed by the geometric-form coefficients rounded to `quantum`, or `None` to disable
+    ///
+    /// Polynomials with the same geometric form (such as scalar multiples of each
+    /// other) then share one summation. Coefficients `t₂, t₃, ...` are rounded to the
+    /// nearest multiple of `quantum`, so a reused sum may belong to coefficients up to
+    /// `quantum / 2` away: keep it near the precision you need (e.g. `1e-12`), as a
+    /// coarse quantum trades accuracy for hits. Changing the setting clears the memo.
+    pub fn set_series_memoization(&mut self, quantum: Option<HighPrecFloat>) {
+        self.series_quantum = quantum;
+        self.series_memo.clear();
+    }
+
+    /// Number of series sums served from the memo since creation or the last `reset`
+    pub fn series_memo_hits(&self) -> usize {
+        self.series_memo_hits
+    }
+
+    /// Solve a polynomial in geometric form, reusing a memoized sum when enabled
+    fn solve_geometric_form(
+        &mut self,
+        form: &GeometricForm,
+        progress: &mut dyn FnMut(SeriesLevel),
+    ) -> Result<HighPrecFloat, SolverError> {
+        let polygon_sizes = self.max_polygon_size();
+        if self.debug_mode && form.polygon_coefficients().iter().skip(polygon_sizes).any(|&t| t != 0.0) {
+            println!(
+                "Warning: max polygon size {} drops terms of degree above {}",
+                polygon_sizes,
+                polygon_sizes + 1
             );
         }
+
+        let quantum = match self.series_quantum {
+            Some(quantum) => quantum,
+            None => return self.sum_geometric_series(form, progress),
+        };
+
+        let quantized = (0..polygon_sizes)
+            .map(|i| (form.polygon_t(i) / quantum).round() as i64)
+            .collect();
+        let key = (self.max_terms, polygon_sizes, quantized);
+
+        if let Some((levels, dominant_type)) = self.series_memo.get(&key) {
+            if self.debug_mode {
+                println!("Reusing memoized series sum");
+            }
+            self.series_memo_hits += 1;
+            self.dominant_type = dominant_type.clone();
+            levels.iter().for_each(|&level| progress(level));
+            return Ok(levels.last().map_or(0.0, |level| level.partial_sum));
+        }
+
+        let mut levels = Vec::with_capacity(self.max_terms);
+        let result = self.sum_geometric_series(form, &mut |level| {
+            levels.push(level);
+            progress(level);
+        })?;
+        self.series_memo.insert(key, (levels, self.dominant_type.clone()));
+        Ok(result)
     }
 
-    /// Solve a polynomial in geometric form: 1 - a + t₂a² + t₃a³ + ... = 0
-    fn solve_geometric_form(&mut self, t_coefficients: &[HighPrecFloat]) -> HighPrecFloat {
+    /// Sum the series for a polynomial in geometric form: 1 - a + t₂a² + t₃a³ + ... = 0
+    fn sum_geometric_series(
+        &mut self,
+        form: &GeometricForm,
+        progress: &mut dyn FnMut(SeriesLevel),
+    ) -> Result<HighPrecFloat, SolverError> {
         let mut result = 0.0;
         let mut term_count = 0;
+        let mut largest_term: HighPrecFloat = -1.0;
 
         if self.debug_mode {
             println!("Geometric form polynomial: 1 - a");
-            for i in 2..t_coefficients.len() {
-                if t_coefficients[i] != 0.0 {
-                    println!(" + {}a^{}", t_coefficients[i], i);
+            for (k, &t) in (2..).zip(form.polygon_coefficients()) {
+                if t != 0.0 {
+                    println!(" + {}a^{}", t, k);
                 }
             }
             println!(" = 0");
             println!("Calculating Hyper-Catalan coefficients:");
         }
 
+        // Table of powers t_{i+2}^k, extended by one exponent per face level since no
+        // count can exceed the total number of faces
+        let polygon_sizes = self.max_polygon_size();
+        let mut powers: Vec<Vec<HighPrecFloat>> = (0..polygon_sizes)
+            .map(|_| vec![1.0])
+            .collect();
+
         // Iterate through all possible types of subdigons up to max_terms
         for total_faces in 0..self.max_terms {
             if self.debug_mode {
                 println!("For total_faces = {}:", total_faces);
             }
 
-            let types = self.generate_types(total_faces, self.max_degree - 1);
-
-            if self.debug_mode {
-                println!("  Generated {} subdigon types", types.len());
+            if total_faces > 0 {
+                for (i, row) in powers.iter_mut().enumerate() {
+                    row.push(form.polygon_t(i).powi(total_faces as i32));
+                }
             }
 
-            for type_vec in &types {
-                let type_ = SubdigonType::new(type_vec.clone());
+            let mut level_count = 0;
+            for type_ in SubdigonTypes::new(total_faces, polygon_sizes) {
+                level_count += 1;
+
+                if self.max_terms_evaluated.is_some_and(|cap| term_count >= cap) {
+                    if self.debug_mode {
+                        println!("Work budget of {} terms exhausted", term_count);
+                    }
+                    return Err(SolverError::NotConverged { residual: None });
+                }
 
                 // Calculate Hyper-Catalan number
                 let c_m = self.calculator.calculate(&type_);
@@ -115,22 +413,38 @@ impl HyperCatalanPolynomialSolver {
                 // Convert the BigRational to f64 for further calculations
                 let c_m_float = c_m.to_f64().unwrap_or(0.0);
 
-                // Calculate product t₂^m₂ · t₃^m₃ · t₄^m₄ · ...
+                // Calculate product t₂^m₂ · t₃^m₃ · t₄^m₄ · ..., where polygons beyond the
+                // polynomial's degree have t = 0
                 let mut term_product = 1.0;
                 for (i, &count) in type_.m.iter().enumerate() {
-                    if count > 0 && i + 2 < t_coefficients.len() {
-                        term_product *= t_coefficients[i + 2].powi(count);
+                    if count > 0 {
+                        term_product *= powers[i][count as usize];
                     }
                 }
 
                 let term = c_m_float * term_product;
                 result += term;
                 term_count += 1;
+                if term.abs() > largest_term {
+                    largest_term = term.abs();
+                    self.dominant_type = Some(type_.clone());
+                }
 
                 if self.debug_mode && term.abs() > 1e-10 {
-                    println!("  C_{} = {}, term = {}", type_.to_string(), c_m, term);
+                    println!("  C_{} = {}, term = {}", type_, c_m, term);
                 }
             }
+
+            if self.debug_mode {
+                println!("  Evaluated {} subdigon types", level_count);
+            }
+
+            progress(SeriesLevel {
+                levels_completed: total_faces + 1,
+                terms_used: term_count,
+                partial_sum: result,
+                root_estimate: result,
+            });
         }
 
         if self.debug_mode {
@@ -138,78 +452,584 @@ impl HyperCatalanPolynomialSolver {
             println!("Series calculation result: {}", result);
         }
 
+        Ok(result)
+    }
+
+    /// Sum the series for a geometric form with complex `tₖ`
+    ///
+    /// The same subdigon types and Hyper-Catalan numbers as `sum_geometric_series`,
+    /// with the products `t₂^m₂ · t₃^m₃ · ...` taken in `Complex<f64>`.
+    fn sum_geometric_series_complex(&mut self, t_coefficients: &[Complex<f64>]) -> Result<Complex<f64>, SolverError> {
+        let mut result = Complex::zero();
+        let mut term_count = 0;
+        for total_faces in 0..self.max_terms {
+            for type_ in SubdigonTypes::new(total_faces, self.max_polygon_size()) {
+                if self.max_terms_evaluated.is_some_and(|cap| term_count >= cap) {
+                    return Err(SolverError::NotConverged { residual: None });
+                }
+                term_count += 1;
+
+                let c_m_float = self.calculator.calculate(&type_).to_f64().unwrap_or(0.0);
+                let mut term = Complex::new(c_m_float, 0.0);
+                for (i, &count) in type_.m.iter().enumerate() {
+                    if count > 0 {
+                        let t = t_coefficients.get(i + 2).copied().unwrap_or_else(Complex::zero);
+                        term *= t.powu(count as u32);
+                    }
+                }
+                result += term;
+            }
+        }
+        if self.debug_mode {
+            println!("Complex series calculation result: {} ({} terms)", result, term_count);
+        }
+        Ok(result)
+    }
+
+    /// Write out the truncated series symbolically, one string per term
+    ///
+    /// Each term is rendered like `[C_(2,1) = 21] * t_2^2 * t_3`, naming the subdigon
+    /// type and its exact Hyper-Catalan number, for every type with at most
+    /// `max_faces` faces over `max_polygon_size` polygon sizes. The terms are in
+    /// enumeration order, so the list can be checked against the paper by hand.
+    pub fn series_symbolic(&mut self, max_faces: usize, max_polygon_size: usize) -> Vec<String> {
+        let mut terms = Vec::new();
+        for total_faces in 0..=max_faces {
+            for type_ in SubdigonTypes::new(total_faces, max_polygon_size) {
+                let mut term = format!("[C_{} = {}]", type_, self.calculator.calculate(&type_));
+                for (i, &count) in type_.m.iter().enumerate() {
+                    match count {
+                        0 => {}
+                        1 => term.push_str(&format!(" * t_{}", i + 2)),
+                        _ => term.push_str(&format!(" * t_{}^{}", i + 2, count)),
+                    }
+                }
+                terms.push(term);
+            }
+        }
+        terms
+    }
+
+    /// The series for the geometric-form root `a` as `(monomial, coefficient)` pairs
+    ///
+    /// `a = Σ C_m · t₂^m₂ · t₃^m₃ · ...` over subdigon types `m`, so each type names a
+    /// monomial in the `tᵢ` and its Hyper-Catalan number is the exact coefficient.
+    /// Covers every type with at most `max_faces` faces over `max_polygon_size`
+    /// polygon sizes, in the order `solve_polynomial` sums them.
+    pub fn root_power_series(&mut self, max_faces: usize) -> Vec<(SubdigonType, BigRational)> {
+        let polygon_sizes = self.max_polygon_size();
+        let mut series = Vec::new();
+        for total_faces in 0..=max_faces {
+            for type_ in SubdigonTypes::new(total_faces, polygon_sizes) {
+                let coefficient = self.calculator.calculate(&type_);
+                series.push((type_, coefficient));
+            }
+        }
+        series
+    }
+
+    /// List every series term as `(type, C_m · t₂^m₂ · t₃^m₃ · ...)`
+    ///
+    /// Covers the same subdigon types, in the same order, as the summation used by
+    /// `solve_polynomial` for the geometric-form coefficients `t_coefficients`
+    /// (`t₀ = 1`, `t₁ = -1`, then `t₂, t₃, ...`), so the contributions add up to the
+    /// series value. Sort by magnitude to find the dominant terms.
+    pub fn series_term_contributions(
+        &mut self,
+        t_coefficients: &[HighPrecFloat],
+    ) -> Vec<(SubdigonType, HighPrecFloat)> {
+        let mut contributions = Vec::new();
+        for total_faces in 0..self.max_terms {
+            for type_ in SubdigonTypes::new(total_faces, self.max_polygon_size()) {
+                let c_m_float = self.calculator.calculate(&type_).to_f64().unwrap_or(0.0);
+
+                let mut term_product = 1.0;
+                for (i, &count) in type_.m.iter().enumerate() {
+                    if count > 0 {
+                        term_product *= t_coefficients.get(i + 2).copied().unwrap_or(0.0).powi(count);
+                    }
+                }
+
+                contributions.push((type_, c_m_float * term_product));
+            }
+        }
+        contributions
+    }
+
+    /// Evaluate the Hyper-Catalan generating function `Σ C_m · t₂^m₂ · t₃^m₃ · ...`
+    ///
+    /// Sums over every subdigon type with at most `max_faces` faces whose polygons
+    /// have a coefficient in `t_coefficients` (indexed like the geometric form, so
+    /// `t_coefficients[i]` is `tᵢ` and entries 0 and 1 are ignored). Independent of
+    /// `max_terms` and `max_degree`; `solve_polynomial` sums the same series with
+    /// `max_faces = max_terms - 1`. With fewer than three entries there are no
+    /// polygons and the sum is 1.
+    pub fn evaluate_series(&mut self, t_coefficients: &[HighPrecFloat], max_faces: usize) -> HighPrecFloat {
+        let polygon_t = t_coefficients.get(2..).unwrap_or(&[]);
+        let polygon_sizes = polygon_t.len();
+        let mut result = 0.0;
+        for total_faces in 0..=max_faces {
+            for type_ in SubdigonTypes::new(total_faces, polygon_sizes) {
+                let c_m_float = self.calculator.calculate(&type_).to_f64().unwrap_or(0.0);
+                let term_product: HighPrecFloat = type_
+                    .m
+                    .iter()
+                    .zip(polygon_t)
+                    .map(|(&count, &t)| t.powi(count))
+                    .product();
+                result += c_m_float * term_product;
+            }
+        }
         result
     }
 
+    /// Estimate how far the `t` coefficients can be scaled before the series diverges
+    ///
+    /// Sums the absolute contributions `Aₙ = Σ|C_m · t₂^m₂ · t₃^m₃ · ...|` of each face
+    /// level `n` up to `max_faces` and applies the ratio test to the last two levels:
+    /// scaling every `tᵢ` by `λ` scales `Aₙ` by `λⁿ`, so the series converges for
+    /// `λ < A_{n-1} / Aₙ`. A result above 1 means the given `t` are inside the region of
+    /// convergence, with room to spare by that factor. This is an empirical estimate,
+    /// accurate as `max_faces` grows; it is infinite when the top levels vanish, as
+    /// for fewer than three entries (no polygons), and `t_coefficients` is indexed
+    /// like `evaluate_series`.
+    pub fn estimate_convergence_radius(&mut self, t_coefficients: &[HighPrecFloat], max_faces: usize) -> HighPrecFloat {
+        let polygon_t = t_coefficients.get(2..).unwrap_or(&[]);
+        let polygon_sizes = polygon_t.len();
+        let max_faces = max_faces.max(2);
+        let mut level_magnitudes = Vec::with_capacity(max_faces + 1);
+        for total_faces in 0..=max_faces {
+            let mut magnitude = 0.0;
+            for type_ in SubdigonTypes::new(total_faces, polygon_sizes) {
+                let c_m_float = self.calculator.calculate(&type_).to_f64().unwrap_or(0.0);
+                let term_product: HighPrecFloat = type_
+                    .m
+                    .iter()
+                    .zip(polygon_t)
+                    .map(|(&count, &t)| t.powi(count))
+                    .product();
+                magnitude += (c_m_float * term_product).abs();
+            }
+            level_magnitudes.push(magnitude);
+        }
+
+        let last = level_magnitudes[max_faces];
+        if last == 0.0 {
+            return HighPrecFloat::INFINITY;
+        }
+        level_magnitudes[max_faces - 1] / last
+    }
+
     /// Solve a general polynomial equation: c₀ + c₁x + c₂x² + ... = 0
-    pub fn solve_polynomial(&mut self, coefficients: &[HighPrecFloat]) -> Result<HighPrecFloat, String> {
-        if coefficients.len() < 2 {
-            return Err("Polynomial must be at least of degree 1".to_string());
+    ///
+    /// With `set_rational_shortcut` enabled, an exact rational root of an integer
+    /// polynomial is returned without summing the series.
+    pub fn solve_polynomial(&mut self, coefficients: &[HighPrecFloat]) -> Result<HighPrecFloat, SolverError> {
+        self.solve_with_status(coefficients).map(|(root, _)| root)
+    }
+
+    /// Solve like `solve_polynomial`, also reporting whether the root passed the residual check
+    fn solve_with_status(&mut self, coefficients: &[HighPrecFloat]) -> Result<(HighPrecFloat, bool), SolverError> {
+        self.dominant_type = None;
+        if self.rational_shortcut {
+            if let Some(root) = smallest_rational_root(self.validate(coefficients)?) {
+                if self.debug_mode {
+                    println!("Found exact rational root {}", root);
+                }
+                return Ok((root, true));
+            }
         }
+        self.solve_checked(coefficients, &mut |_, _| {})
+    }
 
-        if self.debug_mode {
-            println!("Original polynomial:");
-            for i in (0..coefficients.len()).rev() {
-                if coefficients[i] != 0.0 {
-                    if i < coefficients.len() - 1 && coefficients[i] > 0.0 {
-                        print!("+");
+    /// Solve single-precision coefficients with `solve_polynomial`, rounding the root to `f32`
+    ///
+    /// The coefficients are widened to `f64` exactly and the series runs in `f64`, so
+    /// the only extra error is the final rounding, at most half an `f32` ulp (about
+    /// 6e-8 relative). The inputs themselves carry only about 7 significant digits,
+    /// though, so a poorly conditioned root can be far less accurate than that.
+    pub fn solve_polynomial_f32(&mut self, coefficients: &[f32]) -> Result<f32, SolverError> {
+        let widened: Vec<HighPrecFloat> = coefficients.iter().map(|&c| HighPrecFloat::from(c)).collect();
+        self.solve_polynomial(&widened).map(|root| root as f32)
+    }
+
+    /// Solve `p(x) = k` by solving `p(x) - k = 0` with `solve_polynomial`
+    ///
+    /// The caller's coefficients are left untouched. The shifted constant term is what
+    /// the series sees, so `k` changes which roots are reachable: `x² = 2` still has a
+    /// zero linear coefficient and fails with `SolverError::ZeroLinearCoefficient`,
+    /// while `x² = 4` can be solved exactly with `set_rational_shortcut` enabled.
+    pub fn solve_equals(&mut self, coefficients: &[HighPrecFloat], k: HighPrecFloat) -> Result<HighPrecFloat, SolverError> {
+        let mut shifted = coefficients.to_vec();
+        match shifted.first_mut() {
+            Some(constant) => *constant -= k,
+            None => shifted.push(-k),
+        }
+        self.solve_polynomial(&shifted)
+    }
+
+    /// Solve like `solve_polynomial`, returning the root with its residual and conditioning
+    ///
+    /// In best-effort mode (see `set_best_effort`) `converged` is `false` when the root
+    /// is only the best estimate of a series that did not converge. `dominant_type` is
+    /// the subdigon type with the largest term in the series, or `None` when the root
+    /// was found without summing it (an exact rational root).
+    pub fn solve_polynomial_report(&mut self, coefficients: &[HighPrecFloat]) -> Result<SolveReport, SolverError> {
+        let (root, converged) = self.solve_with_status(coefficients)?;
+        Ok(SolveReport {
+            converged,
+            dominant_type: self.dominant_type.clone(),
+            ..SolveReport::for_root(coefficients, root)
+        })
+    }
+
+    /// Solve with the series, then polish with Newton's method, reporting both stages
+    ///
+    /// Returns `(series_root, series_residual, newton_root, newton_residual)` with
+    /// absolute residuals `|p(x)|`, where Newton's method runs for up to
+    /// `newton_iters` iterations from the series root. This is the comparison the
+    /// interactive binary prints. The series root is not held to the residual
+    /// tolerance, since a rough seed is all Newton's method needs.
+    pub fn solve_compare(
+        &mut self,
+        coefficients: &[HighPrecFloat],
+        newton_iters: usize,
+    ) -> Result<(HighPrecFloat, HighPrecFloat, HighPrecFloat, HighPrecFloat), SolverError> {
+        const EPSILON: HighPrecFloat = 1e-15;
+        let series_root = self.solve_observed(coefficients, &mut |_| {})?;
+        let newton_root = self.bootstrap_root(coefficients, series_root, newton_iters, EPSILON);
+        let residual = |x: HighPrecFloat| eval_dual(coefficients, x).0.abs();
+        Ok((series_root, residual(series_root), newton_root, residual(newton_root)))
+    }
+
+    /// Solve a polynomial with rational coefficients, summing the series exactly
+    ///
+    /// The geometric-form coefficients and the truncated series are computed in
+    /// `BigRational` arithmetic, and the exact series value is kept in the report's
+    /// `series_value_exact`; only the root and its diagnostics are rounded to `f64`.
+    /// Balancing, depressing and acceleration do not apply to this path. Exact
+    /// arithmetic is much slower than `solve_polynomial`, and the numbers grow with
+    /// `max_terms`.
+    pub fn solve_polynomial_exact(&mut self, coefficients: &[BigRational]) -> Result<SolveReport, SolverError> {
+        let approximate: Vec<HighPrecFloat> = coefficients.iter()
+            .map(|c| c.to_f64().unwrap_or(HighPrecFloat::NAN))
+            .collect();
+        let len = self.validate(&approximate)?.len();
+        let coefficients = &coefficients[..len];
+        if len < 2 {
+            return Err(SolverError::DegreeTooLow);
+        }
+        let (c0, c1) = (&coefficients[0], &coefficients[1]);
+        if c1.is_zero() {
+            return Err(SolverError::ZeroLinearCoefficient);
+        }
+
+        // tₖ = cₖ·(-c₀/c₁)ᵏ / c₀ = cₖ·(-c₀/c₁)ᵏ⁻¹ / (-c₁), as in `to_geometric_form`
+        let ratio = -c0 / c1;
+        let t: Vec<BigRational> = coefficients.iter()
+            .enumerate()
+            .skip(2)
+            .map(|(k, c)| c * ratio.pow(k as i32 - 1) / -c1)
+            .collect();
+
+        let mut series = BigRational::zero();
+        let mut term_count = 0;
+        for total_faces in 0..self.max_terms {
+            for type_ in SubdigonTypes::new(total_faces, self.max_polygon_size()) {
+                if self.max_terms_evaluated.is_some_and(|cap| term_count >= cap) {
+                    return Err(SolverError::NotConverged { residual: None });
+                }
+                term_count += 1;
+
+                // Polygons beyond the polynomial's degree have t = 0
+                if type_.m.iter().enumerate().any(|(i, &count)| count > 0 && i >= t.len()) {
+                    continue;
+                }
+                let mut term = self.calculator.calculate(&type_);
+                for (t_i, &count) in t.iter().zip(&type_.m) {
+                    if count > 0 {
+                        term *= t_i.pow(count);
                     }
+                }
+                series += term;
+            }
+        }
+
+        if series.is_zero() {
+            return Err(SolverError::ZeroGeometricRoot);
+        }
+        let root = (&ratio * &series).to_f64().unwrap_or(HighPrecFloat::NAN);
+        let mut report = SolveReport::for_root(&approximate[..len], root);
+        report.series_value_exact = Some(series);
+        Ok(report)
+    }
+
+    /// Solve a polynomial with complex coefficients through the complex series
+    ///
+    /// Converts to the geometric form with `to_geometric_form_complex` and sums the
+    /// Hyper-Catalan series in `Complex<f64>`, so the series can reach complex roots
+    /// (it converges to the root the series selects, normally the one nearest zero).
+    /// Errors with `SolverError::ZeroLinearCoefficient` when `c₁ = 0`, as for real
+    /// input; `solve_polynomial_complex_near` shifts the polynomial first to avoid
+    /// that. Balancing, depressing and acceleration do not apply, but the residual
+    /// check of `set_residual_tolerance` does.
+    pub fn solve_polynomial_complex(&mut self, coefficients: &[Complex<f64>]) -> Result<Complex<f64>, SolverError> {
+        if self.max_terms == 0 {
+            return Err(SolverError::NoSeriesTerms);
+        }
+        if let Some(index) = coefficients.iter().position(|c| !c.re.is_finite() || !c.im.is_finite()) {
+            return Err(SolverError::InvalidCoefficient { index });
+        }
+        let coefficients = match coefficients.iter().rposition(|c| !c.is_zero()) {
+            None => return Err(SolverError::ZeroPolynomial),
+            Some(0) => return Err(SolverError::DegreeTooLow),
+            Some(degree) => &coefficients[..=degree],
+        };
+        let degree = coefficients.len() - 1;
+        if degree > self.max_degree {
+            return Err(SolverError::DegreeTooHigh { degree, max_degree: self.max_degree });
+        }
+
+        let t = to_geometric_form_complex(coefficients)?;
+        let a = self.sum_geometric_series_complex(&t)?;
+        if a.is_zero() {
+            return Err(SolverError::ZeroGeometricRoot);
+        }
+        let root = -coefficients[0] * a / coefficients[1];
+
+        if let Some(tolerance) = self.residual_tolerance {
+            let residual = evaluate_polynomial_complex(coefficients, root).norm();
+            let magnitude: HighPrecFloat = coefficients.iter()
+                .enumerate()
+                .map(|(i, c)| c.norm() * root.norm().powi(i as i32))
+                .sum();
+            if !(root.re.is_finite() && root.im.is_finite() && residual <= tolerance * magnitude) {
+                return Err(SolverError::NotConverged { residual: Some(residual) });
+            }
+        }
+        Ok(root)
+    }
+
+    /// Solve through the complex series for the root nearest `center`
+    ///
+    /// Re-expands the polynomial around `center` (a complex Taylor shift), solves the
+    /// shifted polynomial with `solve_polynomial_complex` and shifts the root back.
+    /// This is how the series reaches roots away from zero, and polynomials such as
+    /// `x² + 1` whose linear coefficient vanishes.
+    pub fn solve_polynomial_complex_near(
+        &mut self,
+        coefficients: &[Complex<f64>],
+        center: Complex<f64>,
+    ) -> Result<Complex<f64>, SolverError> {
+        // Repeated synthetic division by (x - center) gives the Taylor coefficients
+        let mut shifted = coefficients.to_vec();
+        for k in 0..shifted.len() {
+            for j in (k..shifted.len() - 1).rev() {
+                let carry = shifted[j + 1] * center;
+                shifted[j] += carry;
+            }
+        }
+        self.solve_polynomial_complex(&shifted).map(|root| root + center)
+    }
 
-                    if i > 0 {
-                        if coefficients[i] == 1.0 {
-                            print!("x");
-                        } else if coefficients[i] == -1.0 {
-                            print!("-x");
-                        } else {
-                            print!("{}x", coefficients[i]);
-                        }
-
-                        if i > 1 {
-                            print!("^{}", i);
-                        }
-                    } else {
-                        print!("{}", coefficients[i]);
+    /// Solve like `solve_polynomial`, reporting progress of the series summation
+    ///
+    /// `progress` is called after each face level with `(levels_completed, max_terms)`,
+    /// so it runs `max_terms` times with the first argument going from 1 to `max_terms`.
+    /// The root is then checked against the residual tolerance (see
+    /// `set_residual_tolerance`).
+    pub fn solve_polynomial_with_progress(
+        &mut self,
+        coefficients: &[HighPrecFloat],
+        mut progress: impl FnMut(usize, usize),
+    ) -> Result<HighPrecFloat, SolverError> {
+        self.solve_checked(coefficients, &mut progress).map(|(root, _)| root)
+    }
+
+    /// Sum the series and apply the residual check, falling back to the best estimate in best-effort mode
+    fn solve_checked(
+        &mut self,
+        coefficients: &[HighPrecFloat],
+        progress: &mut dyn FnMut(usize, usize),
+    ) -> Result<(HighPrecFloat, bool), SolverError> {
+        let max_terms = self.max_terms;
+        let mut estimates = Vec::with_capacity(max_terms);
+        let result = self.solve_observed(coefficients, &mut |level| {
+            estimates.push(level.root_estimate);
+            progress(level.levels_completed, max_terms)
+        });
+
+        let error = match result {
+            Ok(root) => match self.residual_tolerance {
+                Some(tolerance) => {
+                    let residual = eval_dual(coefficients, root).0.abs();
+                    let magnitude: HighPrecFloat = coefficients.iter()
+                        .enumerate()
+                        .map(|(i, c)| c.abs() * root.abs().powi(i as i32))
+                        .sum();
+                    if root.is_finite() && residual <= tolerance * magnitude {
+                        return Ok((root, true));
+                    }
+                    if self.debug_mode {
+                        println!("Rejected root {} with residual {}", root, residual);
                     }
-                    print!(" ");
+                    estimates.push(root);
+                    SolverError::NotConverged { residual: Some(residual) }
+                }
+                None => return Ok((root, true)),
+            },
+            Err(error @ SolverError::NotConverged { .. }) => error,
+            Err(error) => return Err(error),
+        };
+
+        if self.best_effort {
+            let best = estimates.into_iter()
+                .filter(|x| x.is_finite())
+                .map(|x| (x, eval_dual(coefficients, x).0.abs()))
+                .min_by(|a, b| a.1.total_cmp(&b.1));
+            if let Some((root, residual)) = best {
+                if self.debug_mode {
+                    println!("Best effort: returning {} with residual {}", root, residual);
                 }
+                return Ok((root, false));
             }
-            println!("= 0");
         }
+        Err(error)
+    }
 
-        // Convert to geometric form: 1 - a + t₂a² + t₃a³ + ... = 0
-        let mut geometric_coeffs = vec![0.0; coefficients.len()];
-        geometric_coeffs[0] = 1.0; // Constant 1
-        geometric_coeffs[1] = -1.0; // Coefficient for a¹
+    /// Record how the series and the root estimate evolve with each face level
+    ///
+    /// Returns `(terms_used, partial_series_value, root_estimate)` after every level,
+    /// where the root estimate maps the partial geometric-form series back to the
+    /// original variable. The last root estimate is the value `solve_polynomial` returns.
+    pub fn convergence_history(
+        &mut self,
+        coefficients: &[HighPrecFloat],
+    ) -> Result<Vec<(usize, HighPrecFloat, HighPrecFloat)>, SolverError> {
+        let mut history = Vec::with_capacity(self.max_terms);
+        self.solve_observed(coefficients, &mut |level| {
+            history.push((level.terms_used, level.partial_sum, level.root_estimate))
+        })?;
+        Ok(history)
+    }
+
+    /// Solve a polynomial that is already depressed: c₀ + c₁x + ... + cₙ₋₂xⁿ⁻² + cₙxⁿ = 0
+    ///
+    /// Skips the Tschirnhaus transform, so the caller is responsible for the
+    /// `x^{n-1}` coefficient being zero (as returned by `depress`); the polynomial is
+    /// otherwise solved as given. Validation and balancing match `solve_polynomial`.
+    pub fn solve_depressed(&mut self, depressed_coeffs: &[HighPrecFloat]) -> Result<HighPrecFloat, SolverError> {
+        let depressed_coeffs = self.validate(depressed_coeffs)?;
+        self.solve_balanced(depressed_coeffs, &mut |_| {})
+    }
+
+    /// Check the series and degree limits, returning the coefficients without trailing zeros
+    fn validate<'a>(&self, coefficients: &'a [HighPrecFloat]) -> Result<&'a [HighPrecFloat], SolverError> {
+        if self.max_terms == 0 {
+            return Err(SolverError::NoSeriesTerms);
+        }
+
+        let coefficients = trim_coefficients(coefficients)?;
+        let degree = coefficients.len() - 1;
+        if degree > self.max_degree {
+            return Err(SolverError::DegreeTooHigh { degree, max_degree: self.max_degree });
+        }
+        Ok(coefficients)
+    }
+
+    /// Validate, optionally depress and balance, and solve, reporting each face level to `progress`
+    fn solve_observed(
+        &mut self,
+        coefficients: &[HighPrecFloat],
+        progress: &mut dyn FnMut(SeriesLevel),
+    ) -> Result<HighPrecFloat, SolverError> {
+        let coefficients = self.validate(coefficients)?;
+
+        if self.depress {
+            let (depressed, shift) = depress(coefficients);
+            if self.debug_mode {
+                println!("Depressed with shift h = {}: {} = 0", shift, format_polynomial(&depressed));
+            }
+            let mut unshift = |level: SeriesLevel| progress(SeriesLevel {
+                root_estimate: level.root_estimate + shift,
+                ..level
+            });
+            return self.solve_balanced(&depressed, &mut unshift).map(|root| root + shift);
+        }
+
+        self.solve_balanced(coefficients, progress)
+    }
+
+    /// Solve, balancing the coefficients first when enabled
+    fn solve_balanced(
+        &mut self,
+        coefficients: &[HighPrecFloat],
+        progress: &mut dyn FnMut(SeriesLevel),
+    ) -> Result<HighPrecFloat, SolverError> {
+        if self.balance {
+            let (balanced, scale) = balance_coefficients(coefficients);
+            if self.debug_mode {
+                println!("Balanced coefficients with scale s = {}: {:?}", scale, balanced);
+            }
+            let mut rescale = |level: SeriesLevel| progress(SeriesLevel {
+                root_estimate: level.root_estimate * scale,
+                ..level
+            });
+            return self.solve_unbalanced(&balanced, &mut rescale).map(|root| root * scale);
+        }
+
+        self.solve_unbalanced(coefficients, progress)
+    }
 
-        if coefficients[1] == 0.0 {
-            return Err("Coefficient for x^1 cannot be zero for geometric form conversion".to_string());
+    /// Solve a polynomial as given, without any rescaling
+    fn solve_unbalanced(
+        &mut self,
+        coefficients: &[HighPrecFloat],
+        progress: &mut dyn FnMut(SeriesLevel),
+    ) -> Result<HighPrecFloat, SolverError> {
+        if coefficients.len() < 2 {
+            return Err(SolverError::DegreeTooLow);
         }
 
-        for i in 2..coefficients.len() {
-            geometric_coeffs[i] = coefficients[i] / coefficients[1];
+        if self.debug_mode {
+            println!("Original polynomial:");
+            println!("{} = 0", format_polynomial(coefficients));
         }
 
+        // Convert to geometric form: 1 - a + t₂a² + t₃a³ + ... = 0
+        let form = GeometricForm::from_coefficients(coefficients)?;
+
         if self.debug_mode {
             println!("Conversion to geometric form:");
             println!("t₁ = -1");
-            for i in 2..geometric_coeffs.len() {
-                println!("t₍{}₎ = {}", i, geometric_coeffs[i]);
+            for (k, t) in (2..).zip(form.polygon_coefficients()) {
+                println!("t₍{}₎ = {}", k, t);
             }
         }
 
         // Solve using Hyper-Catalan series
-        let root = self.solve_geometric_form(&geometric_coeffs);
+        let mut partial_sums = Vec::with_capacity(self.max_terms);
+        let mut to_original = |level: SeriesLevel| {
+            partial_sums.push(level.partial_sum);
+            progress(SeriesLevel {
+                root_estimate: -coefficients[0] * level.partial_sum / coefficients[1],
+                ..level
+            })
+        };
+        let mut root = self.solve_geometric_form(&form, &mut to_original)?;
 
-        if root == 0.0 {
+        if self.acceleration != Acceleration::None {
+            let accelerated = self.acceleration.apply(&partial_sums);
             if self.debug_mode {
-                println!("Warning: obtained zero root in geometric form, which may lead to division by zero");
+                println!("Accelerated series with {:?}: {} -> {}", self.acceleration, root, accelerated);
+            }
+            if accelerated.is_finite() {
+                root = accelerated;
             }
-            // Return some default value instead of dividing by zero
-            return Ok(1.0);
         }
 
         // Convert back to original polynomial root
-        let original_root = -coefficients[0] / (coefficients[1] * root);
+        let original_root = from_geometric_root(coefficients, root)?;
 
         if self.debug_mode {
             println!("Root in geometric form: a = {}", root);
@@ -234,35 +1054,56 @@ impl HyperCatalanPolynomialSolver {
         iterations: usize,
         epsilon: HighPrecFloat,
     ) -> HighPrecFloat {
+        let history = self.bootstrap_root_history(coefficients, initial_guess, iterations, epsilon);
+        history.last().map_or(initial_guess, |&(x, _)| x)
+    }
+
+    /// Run Newton's method like `bootstrap_root`, failing if the iterates cycle
+    ///
+    /// Returns `SolverError::NewtonCycle` with the last iterate when Newton's method
+    /// falls into a 2-cycle, which happens for some polynomials and starting points
+    /// (e.g. `x³ - 2x + 2` from 0) and otherwise burns every iteration without
+    /// converging. Restart from a different guess in that case.
+    pub fn try_bootstrap_root(
+        &self,
+        coefficients: &[HighPrecFloat],
+        initial_guess: HighPrecFloat,
+        iterations: usize,
+        epsilon: HighPrecFloat,
+    ) -> Result<HighPrecFloat, SolverError> {
+        let history = self.bootstrap_root_history(coefficients, initial_guess, iterations, epsilon);
+        let x = history.last().map_or(initial_guess, |&(x, _)| x);
+        if ends_in_two_cycle(&history) {
+            return Err(SolverError::NewtonCycle { x });
+        }
+        Ok(x)
+    }
+
+    /// Run Newton's method like `bootstrap_root`, recording `(x, f(x))` for every iterate
+    ///
+    /// The first entry is the initial guess and the last entry's `x` is the value
+    /// `bootstrap_root` returns for the same arguments. Iteration also stops early when
+    /// the iterates fall into a 2-cycle, returning to (a relative `1e-12` of) the value
+    /// from two steps back while the middle value differs.
+    pub fn bootstrap_root_history(
+        &self,
+        coefficients: &[HighPrecFloat],
+        initial_guess: HighPrecFloat,
+        iterations: usize,
+        epsilon: HighPrecFloat,
+    ) -> Vec<(HighPrecFloat, HighPrecFloat)> {
         if self.debug_mode {
             println!("Starting Newton's method refinement:");
             println!("Initial guess: {}", initial_guess);
         }
 
-        // Create polynomial function
-        let polynomial_function = |x: HighPrecFloat| -> HighPrecFloat {
-            let mut result = 0.0;
-            for (i, &coeff) in coefficients.iter().enumerate() {
-                result += coeff * x.powi(i as i32);
-            }
-            result
-        };
-
-        // Create derivative function
-        let derivative_function = |x: HighPrecFloat| -> HighPrecFloat {
-            let mut result = 0.0;
-            for i in 1..coefficients.len() {
-                result += (i as HighPrecFloat) * coefficients[i] * x.powi((i as i32) - 1);
-            }
-            result
-        };
-
-        // Apply Newton's method
+        // Apply Newton's method, getting f(x) and f'(x) together from dual numbers
         let mut x = initial_guess;
-        for i in 0..iterations {
-            let f_x = polynomial_function(x);
-            let df_x = derivative_function(x);
+        let (mut f_x, mut df_x) = eval_dual(coefficients, x);
+        let mut history = Vec::with_capacity(iterations + 1);
+        history.push((x, f_x));
 
+        for i in 0..iterations {
             if df_x.abs() < epsilon {
                 if self.debug_mode {
                     println!("Iteration {}: derivative near zero, stopping", i);
@@ -280,16 +1121,27 @@ impl HyperCatalanPolynomialSolver {
                 );
             }
 
+            let previous_f_x = f_x;
             x = new_x;
+            (f_x, df_x) = eval_dual(coefficients, x);
+            history.push((x, f_x));
 
-            if f_x.abs() < epsilon {
+            if ends_in_two_cycle(&history) {
+                if self.debug_mode {
+                    println!("Iteration {}: iterates are cycling between {} and {}, stopping", i, history[history.len() - 2].0, x);
+                }
+                break;
+            }
+
+            if previous_f_x.abs() < epsilon {
                 if self.debug_mode {
                     println!("Iteration {}: function value near zero, stopping", i);
                 }
                 break;
             }
 
-            if delta.abs() < epsilon {
+            let step_tolerance = if self.relative_tolerance { epsilon * (1.0 + x.abs()) } else { epsilon };
+            if delta.abs() < step_tolerance {
                 if self.debug_mode {
                     println!("Iteration {}: change too small, stopping", i);
                 }
@@ -298,12 +1150,31 @@ impl HyperCatalanPolynomialSolver {
         }
 
         if self.debug_mode {
-            let final_error = polynomial_function(x).abs();
             println!("Final root value: {}", x);
-            println!("Error: {}", final_error);
+            println!("Error: {}", f_x.abs());
         }
 
-        x
+        history
+    }
+
+    /// Lazily yield Newton iterates x₀, x₁, x₂, ... starting from the initial guess
+    ///
+    /// No stopping criterion is applied; callers decide when to stop with `take`,
+    /// `take_while` and friends. The sequence ends early only if the derivative
+    /// vanishes, since the next iterate would be undefined.
+    pub fn newton_iterates<'a>(
+        &self,
+        coefficients: &'a [HighPrecFloat],
+        initial_guess: HighPrecFloat,
+    ) -> impl Iterator<Item = HighPrecFloat> + 'a {
+        std::iter::successors(Some(initial_guess), move |&x| {
+            let (f_x, df_x) = eval_dual(coefficients, x);
+            if df_x == 0.0 {
+                None
+            } else {
+                Some(x - f_x / df_x)
+            }
+        })
     }
 
     /// Find a root using only Newton's method without Hyper-Catalan series
@@ -317,4 +1188,606 @@ impl HyperCatalanPolynomialSolver {
         const EPSILON: HighPrecFloat = 1e-15;
         self.bootstrap_root(coefficients, initial_guess, iterations, EPSILON)
     }
-} 
\ No newline at end of file
+
+    /// Run Newton's method exactly over the rationals
+    ///
+    /// Evaluates `f` and `f'` in `BigRational`, so every iterate is the exact Newton
+    /// iterate with no rounding. The number of digits roughly doubles each step along
+    /// with the accuracy, so keep `iterations` small. Stops early at an exact root or
+    /// where the derivative vanishes.
+    pub fn newton_root_exact(
+        &self,
+        coefficients: &[BigRational],
+        initial_guess: BigRational,
+        iterations: usize,
+    ) -> BigRational {
+        let mut x = initial_guess;
+        for _ in 0..iterations {
+            let (f_x, df_x) = eval_rational_with_derivative(coefficients, &x);
+            if f_x.is_zero() || df_x.is_zero() {
+                break;
+            }
+            x -= f_x / df_x;
+        }
+        x
+    }
+
+    /// Run Newton's method over the rationals at a working precision that grows as needed
+    ///
+    /// Each iterate is rounded to a multiple of `2⁻ᵇⁱᵗˢ`, starting at 53 bits (like
+    /// `f64`), so early iterations stay cheap instead of carrying the exact iterates'
+    /// doubling digits. Whenever a step stops reducing `|f(x)|` the precision has run
+    /// out, and the number of bits is doubled, up to `max_bits`. Stops once
+    /// `|f(x)| <= tolerance`, or when no further progress is possible. Returns the
+    /// root and the final number of bits.
+    pub fn newton_root_adaptive_precision(
+        &self,
+        coefficients: &[BigRational],
+        initial_guess: BigRational,
+        tolerance: &BigRational,
+        max_bits: usize,
+    ) -> (BigRational, usize) {
+        const INITIAL_BITS: usize = 53;
+        let mut bits = INITIAL_BITS.min(max_bits);
+        let mut x = round_to_bits(&initial_guess, bits);
+        let mut residual = eval_rational_with_derivative(coefficients, &x).0.abs();
+
+        while residual > *tolerance {
+            let (f_x, df_x) = eval_rational_with_derivative(coefficients, &x);
+            if df_x.is_zero() {
+                break;
+            }
+            let next = round_to_bits(&(&x - f_x / df_x), bits);
+            let next_residual = eval_rational_with_derivative(coefficients, &next).0.abs();
+            if next_residual >= residual {
+                if bits >= max_bits {
+                    break;
+                }
+                bits = (2 * bits).min(max_bits);
+                if self.debug_mode {
+                    println!("Residual stalled at {}, raising precision to {} bits", residual, bits);
+                }
+                continue;
+            }
+            x = next;
+            residual = next_residual;
+        }
+        (x, bits)
+    }
+
+    /// Find every root and report the distinct real roots, their multiplicities and the complex roots
+    ///
+    /// See `classify_roots` for the tolerance deciding real versus complex roots and
+    /// which nearby roots merge into one multiple root.
+    pub fn classify_roots(&mut self, coefficients: &[HighPrecFloat]) -> RootSummary {
+        classify_roots(coefficients)
+    }
+
+    /// Discover real roots by restarting Newton's method from many random guesses
+    ///
+    /// Initial guesses are drawn uniformly from `[-B, B]`, where `B` is the Cauchy bound
+    /// containing every root, using a generator seeded with `seed` so results are
+    /// reproducible. Runs whose residual is not small relative to the size of the terms
+    /// are discarded, and limits within a relative `1e-8` of each other are merged.
+    /// Returns the distinct roots in increasing order.
+    pub fn find_roots_random_restart(
+        &self,
+        coefficients: &[HighPrecFloat],
+        attempts: usize,
+        seed: u64,
+    ) -> Vec<HighPrecFloat> {
+        self.find_roots_random_restart_with_rng(coefficients, attempts, &mut SplitMix64::new(seed))
+    }
+
+    /// Discover real roots like `find_roots_random_restart`, drawing guesses from `rng`
+    pub fn find_roots_random_restart_with_rng(
+        &self,
+        coefficients: &[HighPrecFloat],
+        attempts: usize,
+        rng: &mut impl RandomSource,
+    ) -> Vec<HighPrecFloat> {
+        const EPSILON: HighPrecFloat = 1e-15;
+        let bound = cauchy_bound(coefficients);
+
+        let mut roots: Vec<HighPrecFloat> = Vec::new();
+        for _ in 0..attempts {
+            let guess = rng.next_f64(-bound, bound);
+            let x = self.bootstrap_root(coefficients, guess, 100, EPSILON);
+
+            let scale: HighPrecFloat = coefficients.iter()
+                .enumerate()
+                .map(|(i, c)| c.abs() * x.abs().powi(i as i32))
+                .sum();
+            let (residual, _) = eval_dual(coefficients, x);
+            if !x.is_finite() || residual.abs() > 1e-8 * scale {
+                continue;
+            }
+
+            if !roots.iter().any(|&root| (root - x).abs() <= 1e-8 * (1.0 + x.abs())) {
+                roots.push(x);
+            }
+        }
+
+        roots.sort_by(HighPrecFloat::total_cmp);
+        roots
+    }
+
+    /// Find the real roots of a polynomial by repeated solving and deflation
+    ///
+    /// Each root is seeded by the Hyper-Catalan series on the current deflated
+    /// polynomial (falling back to the Cauchy bound, above every root, when the series
+    /// cannot be applied), refined by
+    /// Newton's method, polished against the original polynomial, and divided out.
+    /// The series converges to the root nearest zero, so by default roots are found
+    /// smallest first and divided out with the stable `deflate_forward`; with
+    /// `DeflationOrder::LargestFirst` the series is applied to the reversed polynomial,
+    /// whose smallest root is the reciprocal of the largest, and `deflate_backward` is
+    /// used instead (see `set_deflation_order`).
+    /// Stops once Newton's method no longer reaches a real root, so complex pairs
+    /// are not reported. Near copies left by deflation or by a multiple root are
+    /// merged with `dedup_roots`; the distinct roots are returned in increasing order.
+    pub fn solve_all_roots(&mut self, coefficients: &[HighPrecFloat]) -> Vec<HighPrecFloat> {
+        const EPSILON: HighPrecFloat = 1e-15;
+        const DISTINCT_TOLERANCE: HighPrecFloat = 1e-6;
+
+        let original = match trim_coefficients(coefficients) {
+            Ok(original) => original,
+            Err(_) => return Vec::new(),
+        };
+
+        let mut current = original.to_vec();
+        let mut roots = Vec::new();
+        while current.len() > 1 {
+            let seed = match self.deflation_order {
+                DeflationOrder::SmallestFirst => self.solve_polynomial(&current).ok(),
+                DeflationOrder::LargestFirst => {
+                    let reversed: Vec<HighPrecFloat> = current.iter().rev().copied().collect();
+                    self.solve_polynomial(&reversed).ok().map(|y| 1.0 / y)
+                }
+            };
+            let seed = seed
+                .filter(|x| x.is_finite())
+                .unwrap_or_else(|| cauchy_bound(&current));
+            let x = self.bootstrap_root(&current, seed, 100, EPSILON);
+            let x = self.bootstrap_root(original, x, 20, EPSILON);
+
+            let scale: HighPrecFloat = current.iter()
+                .enumerate()
+                .map(|(i, c)| c.abs() * x.abs().powi(i as i32))
+                .sum();
+            let (residual, _) = eval_dual(&current, x);
+            if !x.is_finite() || residual.abs() > 1e-8 * scale {
+                break;
+            }
+
+            roots.push(x);
+            current = match self.deflation_order {
+                DeflationOrder::SmallestFirst => deflate_forward(&current, x),
+                DeflationOrder::LargestFirst if x != 0.0 => deflate_backward(&current, x),
+                DeflationOrder::LargestFirst => deflate_forward(&current, x),
+            };
+        }
+
+        dedup_roots(&mut roots, DISTINCT_TOLERANCE);
+        roots
+    }
+
+    /// Find every root with the algorithm chosen by `set_method`
+    ///
+    /// One entry point for comparing methods without changing call sites. Only
+    /// `Method::SeriesNewton` is limited to real roots; the others return all `n`
+    /// complex roots of a degree-`n` polynomial. Every method returns its roots in
+    /// the order of `sort_roots`.
+    pub fn solve_all(&mut self, coefficients: &[HighPrecFloat]) -> Vec<Complex<f64>> {
+        match self.method {
+            Method::SeriesNewton => {
+                let mut roots: Vec<Complex<f64>> = self.solve_all_roots(coefficients)
+                    .into_iter()
+                    .map(|x| Complex::new(x, 0.0))
+                    .collect();
+                sort_roots(&mut roots);
+                roots
+            }
+            #[cfg(feature = "nalgebra")]
+            Method::CompanionMatrix => companion_roots(coefficients),
+            Method::DurandKerner => durand_kerner(coefficients, DURAND_KERNER_ITERATIONS, DURAND_KERNER_TOLERANCE),
+            Method::Laguerre => solve_all_real_poly(coefficients),
+        }
+    }
+
+    /// Find all complex roots, preferring the series and falling back to Durand–Kerner
+    ///
+    /// See `solve_robust_with_path`, which also reports which method was used.
+    pub fn solve_robust(&mut self, coefficients: &[HighPrecFloat]) -> Vec<Complex<f64>> {
+        self.solve_robust_with_path(coefficients).0
+    }
+
+    /// Find all complex roots, reporting whether the series or the fallback was used
+    ///
+    /// A palindromic polynomial of even degree is handed to `reciprocal_roots`, and an
+    /// even or odd one to `parity_roots`, each solving it at half the degree. Otherwise
+    /// `solve_polynomial` is tried first. When it succeeds, the series root is polished
+    /// with Newton's method, divided out, and the remaining roots are found by
+    /// `durand_kerner` on the deflated polynomial.
+    /// When the series fails for any reason (too high a degree, a zero linear
+    /// coefficient, or a residual showing it did not converge), `durand_kerner` solves
+    /// the whole polynomial instead. Under debug mode the chosen path is printed. In
+    /// every case the roots are returned in the order of `sort_roots`.
+    pub fn solve_robust_with_path(&mut self, coefficients: &[HighPrecFloat]) -> (Vec<Complex<f64>>, RobustPath) {
+        const EPSILON: HighPrecFloat = 1e-15;
+
+        if let Some(roots) = reciprocal_roots(coefficients) {
+            if self.debug_mode {
+                println!("Robust solve: palindromic polynomial, solving in x + 1/x");
+            }
+            return (roots, RobustPath::Reciprocal);
+        }
+        if let Some(roots) = parity_roots(coefficients) {
+            if self.debug_mode {
+                println!("Robust solve: even or odd polynomial, solving in x^2");
+            }
+            return (roots, RobustPath::Parity);
+        }
+
+        match self.solve_polynomial(coefficients) {
+            Ok(series_root) => {
+                let root = self.bootstrap_root(coefficients, series_root, 50, EPSILON);
+                if self.debug_mode {
+                    println!("Robust solve: series converged to {}, polished to {}", series_root, root);
+                }
+                let original = trim_coefficients(coefficients).unwrap_or(coefficients);
+                let mut roots = vec![Complex::new(root, 0.0)];
+                roots.extend(durand_kerner(&deflate_forward(original, root), DURAND_KERNER_ITERATIONS, DURAND_KERNER_TOLERANCE));
+                sort_roots(&mut roots);
+                (roots, RobustPath::Series)
+            }
+            Err(error) => {
+                if self.debug_mode {
+                    println!("Robust solve: series failed ({}), falling back to Durand-Kerner", error);
+                }
+                (durand_kerner(coefficients, DURAND_KERNER_ITERATIONS, DURAND_KERNER_TOLERANCE), RobustPath::DurandKerner)
+            }
+        }
+    }
+
+    /// Find every root with `solve_robust` and return the one closest to `guess`
+    ///
+    /// Suited to tracking a root as a parameter changes, passing the previous root as
+    /// the guess. Real and complex roots are considered alike by the distance
+    /// `|root - guess|`. Returns `None` for a constant polynomial, which has no roots.
+    pub fn nearest_root(&mut self, coefficients: &[HighPrecFloat], guess: Complex<f64>) -> Option<Complex<f64>> {
+        self.solve_robust(coefficients)
+            .into_iter()
+            .min_by(|a, b| (a - guess).norm().total_cmp(&(b - guess).norm()))
+    }
+
+    /// Find the real roots like `solve_all_roots`, each paired with its residual
+    ///
+    /// Returns `(root, |p(root)|)` in increasing order of root, with the residual
+    /// measured against the original polynomial rather than a deflated one, so roots
+    /// spoiled by deflation drift can be filtered out by the caller.
+    pub fn solve_all_roots_with_residuals(
+        &mut self,
+        coefficients: &[HighPrecFloat],
+    ) -> Vec<(HighPrecFloat, HighPrecFloat)> {
+        self.solve_all_roots(coefficients)
+            .into_iter()
+            .map(|root| (root, evaluate_polynomial(coefficients, root).abs()))
+            .collect()
+    }
+
+    /// Find all complex roots by homotopy continuation
+    ///
+    /// Tracks the roots of `H(x, t) = (1 - t)·γ·q(x) + t·p(x)` from `t = 0` to `t = 1`,
+    /// where `p` is the (monic-normalized) target polynomial and the start system is
+    /// `q(x) = xⁿ - 1`, whose roots are the n-th roots of unity. The fixed complex
+    /// constant `γ` (the "gamma trick") keeps the paths away from singularities for all
+    /// but a measure-zero set of inputs. Each of the `steps` increments of `t` is
+    /// followed by a few Newton corrections, and the endpoints are polished on `p`
+    /// and returned in the order of `sort_roots`.
+    pub fn solve_homotopy(&self, coefficients: &[Complex<f64>], steps: usize) -> Vec<Complex<f64>> {
+        let zero = Complex::new(0.0, 0.0);
+
+        // Trim zero leading coefficients and normalize to a monic polynomial
+        let len = coefficients.iter().rposition(|c| *c != zero).map_or(0, |i| i + 1);
+        if len < 2 {
+            return Vec::new();
+        }
+        let leading = coefficients[len - 1];
+        let target: Vec<Complex<f64>> = coefficients[..len].iter().map(|c| c / leading).collect();
+        let degree = len - 1;
+
+        let mut start = vec![zero; len];
+        start[0] = Complex::new(-1.0, 0.0);
+        start[degree] = Complex::new(1.0, 0.0);
+
+        let gamma = Complex::new(0.6, 0.8);
+        let steps = steps.max(1);
+
+        let mut roots: Vec<Complex<f64>> = (0..degree)
+            .map(|k| Complex::from_polar(1.0, 2.0 * std::f64::consts::PI * k as f64 / degree as f64))
+            .collect();
+
+        for step in 1..=steps {
+            let t = step as f64 / steps as f64;
+            for root in roots.iter_mut() {
+                for _ in 0..5 {
+                    let (q, dq) = eval_complex_with_derivative(&start, *root);
+                    let (p, dp) = eval_complex_with_derivative(&target, *root);
+                    let h = gamma * q * (1.0 - t) + p * t;
+                    let dh = gamma * dq * (1.0 - t) + dp * t;
+                    if dh == zero {
+                        break;
+                    }
+                    *root -= h / dh;
+                }
+            }
+
+            if self.debug_mode && step % (steps / 10).max(1) == 0 {
+                println!("Homotopy t = {:.3}: {:?}", t, roots);
+            }
+        }
+
+        // Polish the endpoints on the target polynomial
+        for root in roots.iter_mut() {
+            for _ in 0..20 {
+                let (p, dp) = eval_complex_with_derivative(&target, *root);
+                if dp == zero {
+                    break;
+                }
+                let delta = p / dp;
+                *root -= delta;
+                if delta.norm() < 1e-15 * (1.0 + root.norm()) {
+                    break;
+                }
+            }
+        }
+
+        sort_roots(&mut roots);
+        roots
+    }
+}
+
+/// Drop zero high-order coefficients, rejecting non-finite, zero and constant polynomials
+fn trim_coefficients(coefficients: &[HighPrecFloat]) -> Result<&[HighPrecFloat], SolverError> {
+    if let Some(index) = coefficients.iter().position(|c| !c.is_finite()) {
+        return Err(SolverError::InvalidCoefficient { index });
+    }
+    match coefficients.iter().rposition(|&c| c != 0.0) {
+        None => Err(SolverError::ZeroPolynomial),
+        Some(0) => Err(SolverError::DegreeTooLow),
+        Some(degree) => Ok(&coefficients[..=degree]),
+    }
+}
+
+/// Estimate how many Newton iterations reduce `initial_residual` to `target`
+///
+/// Quadratic convergence roughly doubles the number of correct digits per step, so
+/// gaining the `ln(initial_residual / target)` (natural) digits needed takes about
+/// `⌈log₂ ln(initial_residual / target)⌉` steps, and at least one when there is
+/// anything to gain. This assumes the iteration is already in the basin of quadratic
+/// convergence of a simple root: far from the root, or at a multiple root where
+/// convergence is only linear, it underestimates. Returns 0 when
+/// `initial_residual <= target`.
+pub fn estimate_newton_iterations(initial_residual: HighPrecFloat, target: HighPrecFloat) -> usize {
+    if initial_residual <= target {
+        return 0;
+    }
+    (initial_residual / target).ln().log2().ceil().max(1.0) as usize
+}
+
+/// Estimate the order of convergence of a Newton history from `bootstrap_root_history`
+///
+/// The steps `δₙ = |xₙ₊₁ - xₙ|` stand in for the unknown errors, each run of three
+/// steps gives `q ≈ ln(δₙ₊₁/δₙ) / ln(δₙ/δₙ₋₁)`, and the median over all runs is
+/// returned. This ratio form of `ln|eₙ₊₁| / ln|eₙ|` cancels the constant in
+/// `eₙ₊₁ ≈ C·eₙ^q`, and the median discards the first steps far from the root and
+/// the last ones near rounding noise; steps below a relative `1e-12` are dropped.
+/// Near 2 means quadratic convergence to a simple root; near 1 means linear
+/// convergence, the sign of a multiple root. Returns NaN when the history has fewer
+/// than three usable steps.
+pub fn estimate_convergence_order(history: &[(HighPrecFloat, HighPrecFloat)]) -> HighPrecFloat {
+    const NOISE_FLOOR: HighPrecFloat = 1e-12;
+    let steps: Vec<HighPrecFloat> = history.windows(2)
+        .map(|pair| (pair[1].0 - pair[0].0).abs())
+        .zip(history.iter().skip(1))
+        .take_while(|&(step, &(x, _))| step > NOISE_FLOOR * (1.0 + x.abs()))
+        .map(|(step, _)| step)
+        .collect();
+    let mut orders: Vec<HighPrecFloat> = steps.windows(3)
+        .map(|run| (run[2] / run[1]).ln() / (run[1] / run[0]).ln())
+        .filter(|q| q.is_finite())
+        .collect();
+    if orders.is_empty() {
+        return HighPrecFloat::NAN;
+    }
+    orders.sort_by(|a, b| a.total_cmp(b));
+    orders[orders.len() / 2]
+}
+
+/// A polynomial in geometric form `1 - a + t₂a² + t₃a³ + ... = 0`
+///
+/// Only `t₂, t₃, ...` are stored; the `1 - a` every geometric form starts with is
+/// implied. Entry `i` of `polygon_coefficients` weights the `(i+2)`-gons, so it lines
+/// up with `SubdigonType::m[i]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeometricForm {
+    t: Vec<HighPrecFloat>,
+}
+
+impl GeometricForm {
+    /// Create a geometric form from `[t₂, t₃, ...]`
+    pub fn new(t: Vec<HighPrecFloat>) -> Self {
+        GeometricForm { t }
+    }
+
+    /// Convert `c₀ + c₁x + c₂x² + ... = 0` to the geometric form
+    ///
+    /// Substituting `x = -c₀·a / c₁` and dividing by `c₀` gives
+    /// `tₖ = (-1)ᵏ · cₖ · c₀ᵏ⁻¹ / c₁ᵏ`, so a root `a` of the geometric form gives the
+    /// root `x = -c₀·a / c₁` of the original. Errors with
+    /// `SolverError::ZeroLinearCoefficient` when `c₁ = 0` and `SolverError::DegreeTooLow`
+    /// for fewer than two coefficients. A zero constant term gives all `tₖ = 0`.
+    pub fn from_coefficients(coefficients: &[HighPrecFloat]) -> Result<Self, SolverError> {
+        if coefficients.len() < 2 {
+            return Err(SolverError::DegreeTooLow);
+        }
+        let (c0, c1) = (coefficients[0], coefficients[1]);
+        if c1 == 0.0 {
+            return Err(SolverError::ZeroLinearCoefficient);
+        }
+
+        // -c₀/c₁ is the root of the linear part; tₖ = cₖ·(-c₀/c₁)ᵏ / c₀ avoids dividing by c₀
+        let ratio = -c0 / c1;
+        let t = coefficients.iter()
+            .enumerate()
+            .skip(2)
+            .map(|(k, &c)| c * ratio.powi(k as i32 - 1) / -c1)
+            .collect();
+        Ok(GeometricForm { t })
+    }
+
+    /// Convert a `Polynomial` to the geometric form, see `from_coefficients`
+    pub fn from_polynomial(polynomial: &Polynomial) -> Result<Self, SolverError> {
+        Self::from_coefficients(&polynomial.coefficients)
+    }
+
+    /// The coefficient `tᵢ` of `aⁱ`: 1 for `i = 0`, -1 for `i = 1`, and 0 beyond the degree
+    pub fn t(&self, i: usize) -> HighPrecFloat {
+        match i {
+            0 => 1.0,
+            1 => -1.0,
+            _ => self.t.get(i - 2).copied().unwrap_or(0.0),
+        }
+    }
+
+    /// The coefficient weighting the polygons counted by `SubdigonType::m[index]`, `t_{index+2}`
+    pub fn polygon_t(&self, index: usize) -> HighPrecFloat {
+        self.t.get(index).copied().unwrap_or(0.0)
+    }
+
+    /// The stored coefficients `[t₂, t₃, ...]`
+    pub fn polygon_coefficients(&self) -> &[HighPrecFloat] {
+        &self.t
+    }
+
+    /// The geometric form as a `Polynomial` in `a`, `[1, -1, t₂, t₃, ...]`
+    pub fn to_polynomial(&self) -> Polynomial {
+        let mut coefficients = vec![1.0, -1.0];
+        coefficients.extend_from_slice(&self.t);
+        Polynomial::new(coefficients)
+    }
+}
+
+/// Convert `c₀ + c₁x + c₂x² + ... = 0` to the geometric form `1 - a + t₂a² + t₃a³ + ... = 0`
+///
+/// Returns `[1, -1, t₂, t₃, ...]` (with `t₀ = 1` and `t₁ = -1`), one entry per input
+/// coefficient; see `GeometricForm::from_coefficients` for the substitution and errors.
+pub fn to_geometric_form(coefficients: &[HighPrecFloat]) -> Result<Vec<HighPrecFloat>, SolverError> {
+    GeometricForm::from_coefficients(coefficients).map(|form| form.to_polynomial().coefficients)
+}
+
+/// Convert a polynomial with complex coefficients to the geometric form
+///
+/// The complex counterpart of `to_geometric_form`: the same substitution
+/// `x = -c₀·a / c₁` gives `[1, -1, t₂, t₃, ...]` with complex `tₖ`, and the same
+/// errors for `c₁ = 0` and fewer than two coefficients.
+pub fn to_geometric_form_complex(coefficients: &[Complex<f64>]) -> Result<Vec<Complex<f64>>, SolverError> {
+    if coefficients.len() < 2 {
+        return Err(SolverError::DegreeTooLow);
+    }
+    let (c0, c1) = (coefficients[0], coefficients[1]);
+    if c1.is_zero() {
+        return Err(SolverError::ZeroLinearCoefficient);
+    }
+
+    let ratio = -c0 / c1;
+    let mut geometric = vec![Complex::one(), -Complex::one()];
+    geometric.extend(
+        coefficients.iter()
+            .enumerate()
+            .skip(2)
+            .map(|(k, &c)| c * ratio.powu(k as u32 - 1) / -c1),
+    );
+    Ok(geometric)
+}
+
+/// The geometric form `1 - a + t₂a² + t₃a³ + ...` of a polynomial, as a `Polynomial` in `a`
+///
+/// Wraps `GeometricForm::from_coefficients`, so it can be evaluated, shifted or solved
+/// with the same tools as the original polynomial.
+pub fn geometric_form_polynomial(coefficients: &[HighPrecFloat]) -> Result<Polynomial, SolverError> {
+    GeometricForm::from_coefficients(coefficients).map(|form| form.to_polynomial())
+}
+
+/// Map a root `a` of the geometric form back to the root `x = -c₀·a / c₁` of the original
+///
+/// The inverse of the substitution made by `to_geometric_form`. Errors with
+/// `SolverError::ZeroGeometricRoot` for `a = 0`, which never solves the geometric form,
+/// and with `SolverError::DegreeTooLow` or `SolverError::ZeroLinearCoefficient` when
+/// the conversion itself is undefined.
+pub fn from_geometric_root(coefficients: &[HighPrecFloat], a: HighPrecFloat) -> Result<HighPrecFloat, SolverError> {
+    if coefficients.len() < 2 {
+        return Err(SolverError::DegreeTooLow);
+    }
+    if coefficients[1] == 0.0 {
+        return Err(SolverError::ZeroLinearCoefficient);
+    }
+    if a == 0.0 {
+        return Err(SolverError::ZeroGeometricRoot);
+    }
+    Ok(-coefficients[0] * a / coefficients[1])
+}
+
+/// Evaluate a rational polynomial and its derivative at `x` using Horner's scheme
+fn eval_rational_with_derivative(coefficients: &[BigRational], x: &BigRational) -> (BigRational, BigRational) {
+    let mut f_x = BigRational::zero();
+    let mut df_x = BigRational::zero();
+    for c in coefficients.iter().rev() {
+        df_x = df_x * x + &f_x;
+        f_x = f_x * x + c;
+    }
+    (f_x, df_x)
+}
+
+/// Round `x` to the nearest multiple of `2⁻ᵇⁱᵗˢ`
+fn round_to_bits(x: &BigRational, bits: usize) -> BigRational {
+    let scale = BigRational::from_integer(BigInt::one() << bits);
+    (x * &scale).round() / scale
+}
+
+/// The rational root of smallest magnitude, if the coefficients are small integers
+fn smallest_rational_root(coefficients: &[HighPrecFloat]) -> Option<HighPrecFloat> {
+    const MAX_INTEGER_COEFFICIENT: HighPrecFloat = 1e6;
+    if !coefficients.iter().all(|c| c.fract() == 0.0 && c.abs() <= MAX_INTEGER_COEFFICIENT) {
+        return None;
+    }
+    let integers: Vec<i64> = coefficients.iter().map(|&c| c as i64).collect();
+    rational_roots(&integers)
+        .into_iter()
+        .map(|(p, q)| p as HighPrecFloat / q as HighPrecFloat)
+        .min_by(|a, b| a.abs().total_cmp(&b.abs()))
+}
+
+/// Whether the last iterate is back where it was two steps ago, having moved in between
+fn ends_in_two_cycle(history: &[(HighPrecFloat, HighPrecFloat)]) -> bool {
+    const CYCLE_TOLERANCE: HighPrecFloat = 1e-12;
+    let n = history.len();
+    if n < 3 {
+        return false;
+    }
+    let (x, previous, two_back) = (history[n - 1].0, history[n - 2].0, history[n - 3].0);
+    let tolerance = CYCLE_TOLERANCE * (1.0 + x.abs());
+    history[n - 1].1 != 0.0 && (x - two_back).abs() <= tolerance && (x - previous).abs() > tolerance
+}
+
+/// Evaluate a complex polynomial and its derivative at `x` using Horner's scheme
+fn eval_complex_with_derivative(coefficients: &[Complex<f64>], x: Complex<f64>) -> (Complex<f64>, Complex<f64>) {
+    let mut value = Complex::new(0.0, 0.0);
+    let mut derivative = Complex::new(0.0, 0.0);
+    for &coeff in coefficients.iter().rev() {
+        derivative = derivative * x + value;
+        value = value * x + coeff;
+    }
+    (value, derivative)
+}
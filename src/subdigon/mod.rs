@@ -1,3 +1,5 @@
+use std::cmp::Ordering;
+use std::fmt;
 use std::hash::{Hash, Hasher};
 
 /// Represents a subdigon type with counts of each polygon size
@@ -16,38 +18,216 @@ impl SubdigonType {
     }
 
     /// Calculate the number of faces in the subdigon
-    pub fn faces(&self) -> i32 {
-        self.m.iter().sum()
+    ///
+    /// Counts are summed as `i64`, so no sum of `i32` counts can overflow; the same
+    /// holds for `edges` and `vertices`. None of the three validate the type; that is
+    /// left to `topology`, which is built on the same counts.
+    pub fn faces(&self) -> i64 {
+        self.m.iter().map(|&count| count as i64).sum()
     }
 
     /// Calculate the number of edges in the subdigon
-    pub fn edges(&self) -> i32 {
-        let sum: i32 = self.m.iter()
-            .enumerate()
-            .map(|(i, &count)| (i as i32 + 2) * count)
-            .sum();
-        
-        sum / 2
+    ///
+    /// Truncates when the polygon sides `Σ(i+2)·mᵢ` are odd; `topology` rejects those.
+    pub fn edges(&self) -> i64 {
+        self.side_count() / 2
     }
 
     /// Calculate the number of vertices in the subdigon
-    pub fn vertices(&self) -> i32 {
+    pub fn vertices(&self) -> i64 {
         self.edges() - self.faces() + 2
     }
 
-    /// Get a string representation for debugging
-    pub fn to_string(&self) -> String {
-        format!("({}))", self.m.iter()
+    /// Faces, edges and vertices as unsigned counts, checking the type is realizable
+    ///
+    /// Errors when a count is negative or when the total number of polygon sides
+    /// `Σ(i+2)·mᵢ` is odd, since each edge is shared by exactly two sides.
+    pub fn topology(&self) -> Result<Topology, String> {
+        if let Some(count) = self.m.iter().find(|&&count| count < 0) {
+            return Err(format!("Subdigon type {} has a negative count {}", self, count));
+        }
+        let sides = self.side_count();
+        if sides % 2 != 0 {
+            return Err(format!("Subdigon type {} has an odd number of polygon sides ({})", self, sides));
+        }
+        Ok(Topology {
+            faces: self.faces() as usize,
+            edges: self.edges() as usize,
+            vertices: self.vertices() as usize,
+        })
+    }
+
+    /// Total number of polygon sides, `Σ(i+2)·mᵢ`
+    fn side_count(&self) -> i64 {
+        self.m.iter()
+            .enumerate()
+            .map(|(i, &count)| (i as i64 + 2) * count as i64)
+            .sum()
+    }
+
+    /// Calculate the Euler characteristic V - E + F
+    ///
+    /// With `vertices = edges - faces + 2` this is always 2 (the sphere convention),
+    /// which makes it a cheap consistency check on the combinatorial formulas.
+    pub fn euler_characteristic(&self) -> i64 {
+        self.vertices() - self.edges() + self.faces()
+    }
+}
+
+/// Faces, edges and vertices of a realizable subdigon type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Topology {
+    pub faces: usize,
+    pub edges: usize,
+    pub vertices: usize,
+}
+
+/// String representation for debugging, e.g. `(2,1,0)`
+impl fmt::Display for SubdigonType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({})", self.m.iter()
             .map(|i| i.to_string())
             .collect::<Vec<String>>()
             .join(","))
     }
 }
 
+/// Order by total number of faces, then lexicographically by the counts
+///
+/// Two types compare equal exactly when their count vectors are equal, matching `Eq`.
+impl Ord for SubdigonType {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.faces()
+            .cmp(&other.faces())
+            .then_with(|| self.m.cmp(&other.m))
+    }
+}
+
+impl PartialOrd for SubdigonType {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl Hash for SubdigonType {
     fn hash<H: Hasher>(&self, state: &mut H) {
         for &value in &self.m {
             value.hash(state);
         }
     }
-} 
\ No newline at end of file
+} 
+/// Generate all subdigon types with the given total faces and number of polygon sizes
+///
+/// Materializes the full list; prefer `SubdigonTypes` when the types are only
+/// visited once. Types are produced in increasing lexicographic order of counts.
+pub fn generate_types(total_faces: usize, max_polygon_size: usize) -> Vec<Vec<i32>> {
+    let mut results = Vec::new();
+    let mut current = vec![0; max_polygon_size];
+    generate_types_recursive(&mut results, &mut current, total_faces, 0, max_polygon_size);
+    results
+}
+
+/// Recursive helper for generating subdigon types
+fn generate_types_recursive(
+    results: &mut Vec<Vec<i32>>,
+    current: &mut Vec<i32>,
+    remaining_faces: usize,
+    index: usize,
+    max_polygon_size: usize,
+) {
+    // Base case: reached the end of our vector
+    if index == max_polygon_size {
+        if remaining_faces == 0 {
+            results.push(current.clone());
+        }
+        return;
+    }
+
+    // Try each possible count for the current polygon size
+    for i in 0..=remaining_faces {
+        current[index] = i as i32;
+        generate_types_recursive(
+            results,
+            current,
+            remaining_faces - i,
+            index + 1,
+            max_polygon_size,
+        );
+    }
+}
+
+/// Number of subdigon types `generate_types(total_faces, max_polygon_size)` would produce
+///
+/// Types are the ways to write `total_faces` as an ordered sum of `max_polygon_size`
+/// counts, so by stars and bars there are `C(total_faces + max_polygon_size - 1,
+/// max_polygon_size - 1)` of them. Computed in `O(min(total_faces, max_polygon_size))`
+/// steps without enumerating, saturating at `usize::MAX`.
+pub fn count_subdigon_types(total_faces: usize, max_polygon_size: usize) -> usize {
+    if max_polygon_size == 0 {
+        return usize::from(total_faces == 0);
+    }
+    let n = (total_faces + max_polygon_size - 1) as u128;
+    let k = total_faces.min(max_polygon_size - 1) as u128;
+    let mut count: u128 = 1;
+    for i in 0..k {
+        // Exact at every step: count is C(n - k + i + 1, i + 1) afterwards
+        count = match count.checked_mul(n - k + i + 1) {
+            Some(product) => product / (i + 1),
+            None => return usize::MAX,
+        };
+    }
+    usize::try_from(count).unwrap_or(usize::MAX)
+}
+
+/// Lazy iterator over all subdigon types with a given total number of faces
+///
+/// Yields the same types in the same order as `generate_types`, but keeps only the
+/// current count vector in memory instead of the whole list.
+#[derive(Debug, Clone)]
+pub struct SubdigonTypes {
+    current: Vec<i32>,
+    done: bool,
+}
+
+impl SubdigonTypes {
+    /// Iterate over types with `total_faces` faces spread over `max_polygon_size` sizes
+    pub fn new(total_faces: usize, max_polygon_size: usize) -> Self {
+        let mut current = vec![0; max_polygon_size];
+        let done = match current.last_mut() {
+            Some(last) => {
+                *last = total_faces as i32;
+                false
+            }
+            // With no polygon sizes only the empty type (zero faces) exists
+            None => total_faces != 0,
+        };
+        SubdigonTypes { current, done }
+    }
+}
+
+impl Iterator for SubdigonTypes {
+    type Item = SubdigonType;
+
+    fn next(&mut self) -> Option<SubdigonType> {
+        if self.done {
+            return None;
+        }
+        let item = SubdigonType::new(self.current.clone());
+
+        // Advance to the next composition in lexicographic order: move one face from the
+        // rightmost nonzero count (past index 0) into its left neighbour, and put the rest
+        // of that count into the last position.
+        match (1..self.current.len()).rev().find(|&r| self.current[r] > 0) {
+            Some(r) => {
+                let moved = self.current[r];
+                self.current[r] = 0;
+                self.current[r - 1] += 1;
+                let last = self.current.len() - 1;
+                self.current[last] = moved - 1;
+            }
+            None => self.done = true,
+        }
+
+        Some(item)
+    }
+}
@@ -0,0 +1,163 @@
+//! Systems of two polynomial equations in two unknowns
+//!
+//! Bivariate polynomials are `BivariatePolynomial`s; the public entry point also
+//! accepts their raw `Vec<Vec<f64>>` layout, where `p[i][j]` is the coefficient of
+//! `xⁱ yʲ`, rows may have different lengths and missing entries are zero.
+
+use crate::bivariate::BivariatePolynomial;
+use crate::roots::solve_all_real_poly;
+use crate::solver::HighPrecFloat;
+
+/// Imaginary parts below this (relative to the root's size) are treated as real
+const REAL_TOLERANCE: HighPrecFloat = 1e-7;
+
+/// Determinant by Gaussian elimination with partial pivoting
+fn determinant(mut matrix: Vec<Vec<HighPrecFloat>>) -> HighPrecFloat {
+    let n = matrix.len();
+    let mut det = 1.0;
+    for col in 0..n {
+        let pivot = (col..n)
+            .max_by(|&a, &b| matrix[a][col].abs().total_cmp(&matrix[b][col].abs()))
+            .unwrap_or(col);
+        if matrix[pivot][col] == 0.0 {
+            return 0.0;
+        }
+        if pivot != col {
+            matrix.swap(pivot, col);
+            det = -det;
+        }
+        det *= matrix[col][col];
+        let pivot_row = matrix[col].clone();
+        for row in matrix.iter_mut().skip(col + 1) {
+            let factor = row[col] / pivot_row[col];
+            for (entry, &p) in row.iter_mut().zip(&pivot_row).skip(col) {
+                *entry -= factor * p;
+            }
+        }
+    }
+    det
+}
+
+/// Solve `A·x = b` by Gaussian elimination with partial pivoting
+fn solve_linear(mut a: Vec<Vec<HighPrecFloat>>, mut b: Vec<HighPrecFloat>) -> Option<Vec<HighPrecFloat>> {
+    let n = b.len();
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))?;
+        if a[pivot][col] == 0.0 {
+            return None;
+        }
+        a.swap(pivot, col);
+        b.swap(pivot, col);
+        let pivot_row = a[col].clone();
+        let pivot_b = b[col];
+        for (row, rhs) in a.iter_mut().zip(b.iter_mut()).skip(col + 1) {
+            let factor = row[col] / pivot_row[col];
+            for (entry, &p) in row.iter_mut().zip(&pivot_row).skip(col) {
+                *entry -= factor * p;
+            }
+            *rhs -= factor * pivot_b;
+        }
+    }
+
+    let mut x = vec![0.0; n];
+    for row in (0..n).rev() {
+        let sum: HighPrecFloat = (row + 1..n).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - sum) / a[row][row];
+    }
+    Some(x)
+}
+
+/// Resultant of two univariate polynomials (constant term first) via the Sylvester matrix
+///
+/// The formal degrees are the slice lengths minus one, so zero leading coefficients
+/// are kept; the resultant vanishes exactly when the polynomials share a root (or
+/// both formal leading coefficients are zero).
+pub fn resultant(p: &[HighPrecFloat], q: &[HighPrecFloat]) -> HighPrecFloat {
+    if p.is_empty() || q.is_empty() {
+        return 0.0;
+    }
+    let m = p.len() - 1;
+    let n = q.len() - 1;
+    let size = m + n;
+    if size == 0 {
+        return 1.0;
+    }
+
+    // Rows hold coefficients from the highest degree down, shifted one column per row
+    let mut sylvester = vec![vec![0.0; size]; size];
+    for row in 0..n {
+        for (k, &c) in p.iter().rev().enumerate() {
+            sylvester[row][row + k] = c;
+        }
+    }
+    for row in 0..m {
+        for (k, &c) in q.iter().rev().enumerate() {
+            sylvester[n + row][row + k] = c;
+        }
+    }
+    determinant(sylvester)
+}
+
+/// Real roots of a univariate polynomial via `solve_all_real_poly`
+fn real_roots(coefficients: &[HighPrecFloat]) -> Vec<HighPrecFloat> {
+    solve_all_real_poly(coefficients)
+        .into_iter()
+        .filter(|root| root.im.abs() <= REAL_TOLERANCE * (1.0 + root.re.abs()))
+        .map(|root| root.re)
+        .collect()
+}
+
+/// Find the real common solutions of `f(x, y) = 0` and `g(x, y) = 0`
+///
+/// Eliminates y with the resultant `R(x) = Res_y(f, g)`, whose roots are the x
+/// coordinates of the intersections. `R` is recovered by evaluating the Sylvester
+/// determinant at `deg R + 1` sample points and interpolating, then solved with
+/// `solve_all_real_poly`. Each real x is back-substituted: the real roots of
+/// `f(x, y)` in y that also make `g(x, y)` (nearly) vanish are the matching y values.
+/// Intended for small degrees, where the interpolation is well conditioned. Takes
+/// the `BivariatePolynomial` coefficient layout; see `solve_system_via_resultant`.
+pub fn solve_two_univariate_via_resultant(
+    f: &[Vec<HighPrecFloat>],
+    g: &[Vec<HighPrecFloat>],
+) -> Vec<(HighPrecFloat, HighPrecFloat)> {
+    solve_system_via_resultant(&BivariatePolynomial::new(f.to_vec()), &BivariatePolynomial::new(g.to_vec()))
+}
+
+/// Find the real common solutions of `f(x, y) = 0` and `g(x, y) = 0`
+///
+/// The algorithm behind `solve_two_univariate_via_resultant`, on `BivariatePolynomial`s.
+pub fn solve_system_via_resultant(
+    f: &BivariatePolynomial,
+    g: &BivariatePolynomial,
+) -> Vec<(HighPrecFloat, HighPrecFloat)> {
+    let degree = f.degree_x() * g.degree_y() + g.degree_x() * f.degree_y();
+
+    // Interpolate R(x) from samples at small integers centred on zero
+    let samples: Vec<HighPrecFloat> = (0..=degree)
+        .map(|k| k as HighPrecFloat - degree as HighPrecFloat / 2.0)
+        .collect();
+    let values: Vec<HighPrecFloat> = samples
+        .iter()
+        .map(|&x| resultant(&f.specialize_x(x), &g.specialize_x(x)))
+        .collect();
+    let vandermonde: Vec<Vec<HighPrecFloat>> = samples
+        .iter()
+        .map(|&x| (0..=degree).map(|k| x.powi(k as i32)).collect())
+        .collect();
+    let resultant_coefficients = match solve_linear(vandermonde, values) {
+        Some(coefficients) => coefficients,
+        None => return Vec::new(),
+    };
+
+    let mut solutions = Vec::new();
+    for x in real_roots(&resultant_coefficients) {
+        for y in real_roots(&f.specialize_x(x)) {
+            let scale = 1.0 + x.abs() + y.abs();
+            if g.eval(x, y).abs() <= 1e-6 * scale * scale {
+                solutions.push((x, y));
+            }
+        }
+    }
+    solutions.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+    solutions
+}
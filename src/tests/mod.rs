@@ -1,8 +1,14 @@
 #[cfg(test)]
+#[allow(clippy::module_inception)]
 mod tests {
     use crate::{
         SubdigonType, HyperCatalanCalculator, HyperCatalanPolynomialSolver, evaluate_polynomial
     };
+    use crate::polynomial::{
+        balance_coefficients, depress, format_polynomial, partial_fractions, root_sensitivity, taylor_shift, Polynomial
+    };
+    use crate::modular::roots_mod_p;
+    use crate::rational::rational_roots;
     use approx::assert_abs_diff_eq;
 
     // Test the SubdigonType calculations
@@ -72,4 +78,1986 @@ mod tests {
         assert_abs_diff_eq!(root2, 2.0, epsilon = 1e-10);
         assert_abs_diff_eq!(root3, 3.0, epsilon = 1e-10);
     }
-} 
\ No newline at end of file
+
+    // Test that balancing reduces the coefficient magnitude spread
+    #[test]
+    fn test_balance_coefficients() {
+        // (x - 1e4)(x - 2e4) = x^2 - 3e4x + 2e8
+        let coefficients = vec![2e8, -3e4, 1.0];
+        let (balanced, scale) = balance_coefficients(&coefficients);
+
+        let spread = |c: &[f64]| {
+            let magnitudes: Vec<f64> = c.iter().map(|x| x.abs()).collect();
+            let max = magnitudes.iter().cloned().fold(0.0, f64::max);
+            let min = magnitudes.iter().cloned().fold(f64::INFINITY, f64::min);
+            max / min
+        };
+        assert!(spread(&balanced) < 10.0);
+        assert!(spread(&balanced) < spread(&coefficients));
+
+        // The root 1e4 maps to 1e4 / scale in the balanced polynomial
+        let residual = evaluate_polynomial(&balanced, 1e4 / scale).abs();
+        assert!(residual < 1e-10);
+    }
+
+    // Test the lazy Newton iterates for x^2 - 2
+    #[test]
+    fn test_newton_iterates() {
+        let solver = HyperCatalanPolynomialSolver::new(2, 10);
+        let coefficients = vec![-2.0, 0.0, 1.0];
+
+        let iterates: Vec<f64> = solver.newton_iterates(&coefficients, 1.0).take(6).collect();
+        assert_eq!(iterates.len(), 6);
+        assert_eq!(iterates[0], 1.0);
+
+        // Errors shrink toward sqrt(2)
+        let sqrt2 = 2.0_f64.sqrt();
+        for pair in iterates.windows(2).skip(1) {
+            assert!((pair[1] - sqrt2).abs() <= (pair[0] - sqrt2).abs());
+        }
+        assert_abs_diff_eq!(iterates[5], sqrt2, epsilon = 1e-12);
+    }
+
+    // Test the recorded Newton convergence history
+    #[test]
+    fn test_bootstrap_root_history() {
+        let solver = HyperCatalanPolynomialSolver::new(2, 10);
+        let coefficients = vec![-2.0, 0.0, 1.0];
+
+        let history = solver.bootstrap_root_history(&coefficients, 1.0, 20, 1e-15);
+        let root = solver.bootstrap_root(&coefficients, 1.0, 20, 1e-15);
+
+        assert_eq!(history[0].0, 1.0);
+        assert_eq!(history.last().unwrap().0, root);
+
+        // Residuals decrease until they reach machine precision
+        for pair in history.windows(2) {
+            assert!(pair[1].1.abs() <= pair[0].1.abs() || pair[1].1.abs() < 1e-14);
+        }
+    }
+
+    // Test root finding over GF(5)
+    #[test]
+    fn test_roots_mod_p() {
+        // x^2 - 1 = (x - 1)(x + 1) mod 5
+        assert_eq!(roots_mod_p(&[-1, 0, 1], 5), vec![1, 4]);
+
+        // x^2 + 1 mod 5: 2^2 = 4 = -1 and 3^2 = 9 = -1
+        assert_eq!(roots_mod_p(&[1, 0, 1], 5), vec![2, 3]);
+
+        // x^2 + 1 has no roots mod 3
+        assert!(roots_mod_p(&[1, 0, 1], 3).is_empty());
+    }
+
+    // Test the rational root theorem enumerator
+    #[test]
+    fn test_rational_roots() {
+        // 2x^2 - 3x + 1 = (2x - 1)(x - 1)
+        assert_eq!(rational_roots(&[1, -3, 2]), vec![(1, 2), (1, 1)]);
+
+        // x^3 - x = x(x - 1)(x + 1)
+        assert_eq!(rational_roots(&[0, -1, 0, 1]), vec![(-1, 1), (0, 1), (1, 1)]);
+
+        // x^2 - 2 has no rational roots
+        assert!(rational_roots(&[-2, 0, 1]).is_empty());
+    }
+
+    // Test evaluating a polynomial at a matrix argument
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn test_evaluate_polynomial_matrix() {
+        use crate::polynomial::evaluate_polynomial_matrix;
+        use nalgebra::DMatrix;
+
+        // p(x) = x^2 - 1 at A = [[1, 2], [3, 4]]: A^2 = [[7, 10], [15, 22]]
+        let a = DMatrix::from_row_slice(2, 2, &[1.0, 2.0, 3.0, 4.0]);
+        let result = evaluate_polynomial_matrix(&[-1.0, 0.0, 1.0], &a).unwrap();
+        let expected = DMatrix::from_row_slice(2, 2, &[6.0, 10.0, 15.0, 21.0]);
+        assert_eq!(result, expected);
+
+        // Non-square matrices are rejected
+        let b = DMatrix::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert!(evaluate_polynomial_matrix(&[-1.0, 0.0, 1.0], &b).is_err());
+    }
+
+    // Test that the Euler characteristic is invariant across subdigon types
+    #[test]
+    fn test_euler_characteristic() {
+        for values in [vec![1, 0, 0], vec![2, 0, 0], vec![0, 2, 0], vec![2, 0, 1], vec![3, 2, 1]] {
+            let type_ = SubdigonType::new(values);
+            assert_eq!(type_.euler_characteristic(), 2);
+        }
+    }
+
+    // Test ordering subdigon types by faces, then lexicographically
+    #[test]
+    fn test_subdigon_ordering() {
+        let mut types = vec![
+            SubdigonType::new(vec![0, 2, 0]),
+            SubdigonType::new(vec![1, 0, 0]),
+            SubdigonType::new(vec![2, 0, 0]),
+            SubdigonType::new(vec![0, 0, 1]),
+            SubdigonType::new(vec![1, 1, 0]),
+        ];
+        types.sort();
+
+        let sorted: Vec<Vec<i32>> = types.into_iter().map(|t| t.m).collect();
+        assert_eq!(sorted, vec![
+            vec![0, 0, 1],
+            vec![1, 0, 0],
+            vec![0, 2, 0],
+            vec![1, 1, 0],
+            vec![2, 0, 0],
+        ]);
+    }
+
+    // Test that the lazy type iterator matches the materialized enumeration
+    #[test]
+    fn test_subdigon_types_iterator() {
+        use crate::subdigon::{generate_types, SubdigonTypes};
+
+        for total_faces in 0..7 {
+            for max_polygon_size in 0..5 {
+                let lazy: Vec<Vec<i32>> = SubdigonTypes::new(total_faces, max_polygon_size)
+                    .map(|t| t.m)
+                    .collect();
+                assert_eq!(lazy, generate_types(total_faces, max_polygon_size));
+            }
+        }
+    }
+
+    // Test that cloned solvers give identical results without sharing state
+    #[test]
+    fn test_solver_clone_and_default() {
+        let coefficients = vec![1.0, 5.0, -0.2, 0.1];
+        let mut original = HyperCatalanPolynomialSolver::new(3, 6);
+        let expected = original.solve_polynomial(&coefficients).unwrap();
+        let warmed_cache = original.calculator().cache_size();
+        assert!(warmed_cache > 0);
+
+        let mut clone = original.clone();
+        assert_eq!(clone.calculator().cache_size(), warmed_cache);
+        assert_eq!(clone.solve_polynomial(&coefficients).unwrap(), expected);
+
+        // Reconfiguring the clone leaves the original untouched
+        clone.set_balancing(true);
+        clone.solve_polynomial(&coefficients).unwrap();
+        assert_eq!(original.solve_polynomial(&coefficients).unwrap(), expected);
+        assert_eq!(original.calculator().cache_size(), warmed_cache);
+
+        let mut default_solver = HyperCatalanPolynomialSolver::default();
+        assert!(default_solver.solve_polynomial(&coefficients).is_ok());
+    }
+
+    // Test that homotopy continuation finds all roots of x^5 - x - 1
+    #[test]
+    fn test_solve_homotopy() {
+        use num::Complex;
+
+        let solver = HyperCatalanPolynomialSolver::new(5, 10);
+        let coefficients: Vec<Complex<f64>> = [-1.0, -1.0, 0.0, 0.0, 0.0, 1.0]
+            .iter()
+            .map(|&c| Complex::new(c, 0.0))
+            .collect();
+
+        let roots = solver.solve_homotopy(&coefficients, 100);
+        assert_eq!(roots.len(), 5);
+
+        for (i, root) in roots.iter().enumerate() {
+            let value = root.powu(5) - root - 1.0;
+            assert!(value.norm() < 1e-10);
+            for other in &roots[i + 1..] {
+                assert!((root - other).norm() > 1e-6);
+            }
+        }
+
+        // Exactly one real root, near 1.1673
+        let real: Vec<_> = roots.iter().filter(|r| r.im.abs() < 1e-10).collect();
+        assert_eq!(real.len(), 1);
+        assert_abs_diff_eq!(real[0].re, 1.1673039782614187, epsilon = 1e-12);
+    }
+
+    // Test that the work budget aborts an explosive series summation
+    #[test]
+    fn test_max_terms_evaluated() {
+        use crate::SolverError;
+
+        let coefficients = vec![1.0, 4.0, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1];
+        let mut solver = HyperCatalanPolynomialSolver::new(8, 30);
+        solver.set_max_terms_evaluated(1000);
+
+        let result = solver.solve_polynomial(&coefficients);
+        assert_eq!(result, Err(SolverError::NotConverged { residual: None }));
+    }
+
+    // Test the progress callback during series summation
+    #[test]
+    fn test_solve_polynomial_with_progress() {
+        let coefficients = vec![1.0, 5.0, -0.2, 0.1];
+        let mut solver = HyperCatalanPolynomialSolver::new(3, 8);
+
+        let mut calls = Vec::new();
+        let root = solver
+            .solve_polynomial_with_progress(&coefficients, |level, total| calls.push((level, total)))
+            .unwrap();
+
+        let expected: Vec<(usize, usize)> = (1..=8).map(|level| (level, 8)).collect();
+        assert_eq!(calls, expected);
+        assert_eq!(root, solver.solve_polynomial(&coefficients).unwrap());
+    }
+
+    // Test that the per-term contributions add up to the series value
+    #[test]
+    fn test_series_term_contributions() {
+        let coefficients = vec![1.0, 5.0, -0.2, 0.1];
+        let mut solver = HyperCatalanPolynomialSolver::new(3, 6);
+
+        // Same geometric-form conversion as solve_polynomial
+        let t = crate::solver::to_geometric_form(&coefficients).unwrap();
+        let contributions = solver.series_term_contributions(&t);
+        assert_eq!(contributions.len(), (1..=6).sum::<usize>());
+
+        let series_value: f64 = contributions.iter().map(|(_, term)| term).sum();
+        let root = solver.solve_polynomial(&coefficients).unwrap();
+        assert_abs_diff_eq!(-coefficients[0] * series_value / coefficients[1], root, epsilon = 1e-12);
+    }
+
+    // Test rejection of zero and constant polynomials
+    #[test]
+    fn test_zero_and_constant_polynomials() {
+        use crate::SolverError;
+
+        let mut solver = HyperCatalanPolynomialSolver::new(2, 10);
+        assert_eq!(solver.solve_polynomial(&[0.0]), Err(SolverError::ZeroPolynomial));
+        assert_eq!(solver.solve_polynomial(&[0.0, 0.0, 0.0]), Err(SolverError::ZeroPolynomial));
+        assert_eq!(solver.solve_polynomial(&[5.0]), Err(SolverError::DegreeTooLow));
+        assert_eq!(solver.solve_polynomial(&[5.0, 0.0, 0.0]), Err(SolverError::DegreeTooLow));
+        assert_eq!(solver.solve_polynomial(&[]), Err(SolverError::ZeroPolynomial));
+    }
+
+    // Test inferring max_degree and rejecting polynomials above it
+    #[test]
+    fn test_for_coefficients_and_degree_check() {
+        use crate::SolverError;
+
+        let quartic = vec![1.0, 5.0, -0.2, 0.1, 0.05];
+        let mut small = HyperCatalanPolynomialSolver::new(2, 6);
+        assert_eq!(
+            small.solve_polynomial(&quartic),
+            Err(SolverError::DegreeTooHigh { degree: 4, max_degree: 2 })
+        );
+
+        // Trailing zeros do not count toward the degree
+        let mut fitted = HyperCatalanPolynomialSolver::for_coefficients(&[1.0, 5.0, -0.2, 0.0], 6);
+        assert!(fitted.solve_polynomial(&[1.0, 5.0, -0.2]).is_ok());
+        assert_eq!(
+            fitted.solve_polynomial(&[1.0, 5.0, -0.2, 0.1]),
+            Err(SolverError::DegreeTooHigh { degree: 3, max_degree: 2 })
+        );
+
+        let mut inferred = HyperCatalanPolynomialSolver::for_coefficients(&quartic, 6);
+        assert!(inferred.solve_polynomial(&quartic).is_ok());
+    }
+
+    // Test that a solver with no series terms reports an error instead of a bogus root
+    #[test]
+    fn test_zero_max_terms() {
+        use crate::SolverError;
+
+        let mut solver = HyperCatalanPolynomialSolver::new(2, 0);
+        assert_eq!(solver.solve_polynomial(&[2.0, -3.0, 1.0]), Err(SolverError::NoSeriesTerms));
+    }
+
+    // Test the first Motzkin numbers
+    #[test]
+    fn test_motzkin_numbers() {
+        let mut calculator = HyperCatalanCalculator::new();
+        let expected = [1, 1, 2, 4, 9, 21, 51, 127, 323];
+        for (n, &value) in expected.iter().enumerate() {
+            assert_eq!(calculator.motzkin(n), value.into());
+        }
+    }
+
+    // Test the per-level convergence history of the series
+    #[test]
+    fn test_convergence_history() {
+        let coefficients = vec![1.0, 5.0, -0.2, 0.1];
+        let mut solver = HyperCatalanPolynomialSolver::new(3, 8);
+
+        let history = solver.convergence_history(&coefficients).unwrap();
+        assert_eq!(history.len(), 8);
+
+        // Terms accumulate level by level: 1, 1 + 2, 1 + 2 + 3, ...
+        let terms: Vec<usize> = history.iter().map(|&(terms, _, _)| terms).collect();
+        assert_eq!(terms, vec![1, 3, 6, 10, 15, 21, 28, 36]);
+
+        let root = solver.solve_polynomial(&coefficients).unwrap();
+        assert_eq!(history.last().unwrap().2, root);
+    }
+
+    // Test the Taylor shift and the Tschirnhaus depression built on it
+    #[test]
+    fn test_taylor_shift_and_depress() {
+        // p(x) = x^2 + 2x + 1 = (x + 1)^2, so p(y - 1) = y^2
+        let (depressed, shift) = depress(&[1.0, 2.0, 1.0]);
+        assert_eq!(shift, -1.0);
+        assert_eq!(depressed, vec![0.0, 0.0, 1.0]);
+
+        // x^3 - 6x^2 + 11x - 6 shifted by 2 is y^3 - y
+        assert_eq!(taylor_shift(&[-6.0, 11.0, -6.0, 1.0], 2.0), vec![0.0, -1.0, 0.0, 1.0]);
+        let (depressed, shift) = depress(&[-6.0, 11.0, -6.0, 1.0]);
+        assert_eq!(shift, 2.0);
+        assert_eq!(depressed, vec![0.0, -1.0, 0.0, 1.0]);
+    }
+
+    // Test the closed-form cubic and quartic solvers
+    #[test]
+    fn test_closed_form_cubic_and_quartic() {
+        use crate::closed_form::{solve_cubic, solve_quartic};
+        use num::Complex;
+
+        let sorted = |roots: &[Complex<f64>]| {
+            let mut roots = roots.to_vec();
+            let key = |z: &Complex<f64>| ((z.re * 1e9).round(), (z.im * 1e9).round());
+            roots.sort_by(|a, b| key(a).partial_cmp(&key(b)).unwrap());
+            roots
+        };
+
+        let cubic = sorted(&solve_cubic(&[-6.0, 11.0, -6.0, 1.0]));
+        for (root, expected) in cubic.iter().zip([1.0, 2.0, 3.0]) {
+            assert_abs_diff_eq!(root.re, expected, epsilon = 1e-10);
+            assert_abs_diff_eq!(root.im, 0.0, epsilon = 1e-10);
+        }
+
+        // x^4 - 1 is biquadratic: roots -1, -i, i, 1
+        let quartic = sorted(&solve_quartic(&[-1.0, 0.0, 0.0, 0.0, 1.0]));
+        let expected = [(-1.0, 0.0), (0.0, -1.0), (0.0, 1.0), (1.0, 0.0)];
+        for (root, (re, im)) in quartic.iter().zip(expected) {
+            assert_abs_diff_eq!(root.re, re, epsilon = 1e-12);
+            assert_abs_diff_eq!(root.im, im, epsilon = 1e-12);
+        }
+
+        // (x - 1)(x - 2)(x - 3)(x - 4)
+        let quartic = sorted(&solve_quartic(&[24.0, -50.0, 35.0, -10.0, 1.0]));
+        for (root, expected) in quartic.iter().zip([1.0, 2.0, 3.0, 4.0]) {
+            assert_abs_diff_eq!(root.re, expected, epsilon = 1e-9);
+            assert_abs_diff_eq!(root.im, 0.0, epsilon = 1e-9);
+        }
+    }
+
+    // Test solving on the blocking pool from a tokio runtime
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_solve_polynomial_async() {
+        use crate::async_solver::solve_polynomial_async;
+        use std::sync::{Arc, Mutex};
+
+        let coefficients = vec![1.0, 5.0, -0.2, 0.1];
+        let solver = Arc::new(Mutex::new(HyperCatalanPolynomialSolver::new(3, 8)));
+
+        let root = solve_polynomial_async(solver.clone(), coefficients.clone()).await.unwrap();
+        let expected = solver.lock().unwrap().solve_polynomial(&coefficients).unwrap();
+        assert_eq!(root, expected);
+    }
+
+    // Compile-time check that the solver types can be shared across threads
+    #[test]
+    fn test_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+
+        assert_send_sync::<HyperCatalanCalculator>();
+        assert_send_sync::<HyperCatalanPolynomialSolver>();
+        assert_send_sync::<SubdigonType>();
+    }
+
+    // Stress tests on Wilkinson's polynomial (x - 1)(x - 2)...(x - 10)
+    mod wilkinson {
+        use crate::roots::solve_all_real_poly;
+        use crate::SolveReport;
+
+        fn wilkinson_coefficients(n: usize) -> Vec<f64> {
+            let mut coefficients = vec![1.0];
+            for k in 1..=n {
+                // Multiply by (x - k)
+                let mut next = vec![0.0; coefficients.len() + 1];
+                for (i, &c) in coefficients.iter().enumerate() {
+                    next[i] -= k as f64 * c;
+                    next[i + 1] += c;
+                }
+                coefficients = next;
+            }
+            coefficients
+        }
+
+        #[test]
+        fn test_wilkinson_roots_recovered() {
+            let coefficients = wilkinson_coefficients(10);
+            let mut roots: Vec<f64> = solve_all_real_poly(&coefficients)
+                .iter()
+                .map(|root| {
+                    assert!(root.im.abs() < 1e-3);
+                    root.re
+                })
+                .collect();
+            roots.sort_by(f64::total_cmp);
+
+            assert_eq!(roots.len(), 10);
+            for (root, expected) in roots.iter().zip(1..=10) {
+                assert!((root - expected as f64).abs() < 1e-3);
+            }
+        }
+
+        #[test]
+        fn test_wilkinson_condition_number_flags_instability() {
+            let coefficients = wilkinson_coefficients(10);
+            let interior = SolveReport::for_root(&coefficients, 7.0);
+            assert_eq!(interior.residual, 0.0);
+            assert!(interior.condition_number > 1e5);
+
+            let benign = SolveReport::for_root(&[-4.0, 0.0, 1.0], 2.0);
+            assert!(benign.condition_number < 10.0);
+        }
+    }
+
+    // Test root sensitivities against the analytic values for x^2 - 4
+    #[test]
+    fn test_root_sensitivity() {
+        // p'(2) = 4, so d(root)/dc_i = -2^i / 4
+        let sensitivity = root_sensitivity(&[-4.0, 0.0, 1.0], 2.0);
+        assert_eq!(sensitivity, vec![-0.25, -0.5, -1.0]);
+
+        // Check against a finite difference in c_0: roots of x^2 - (4 - h) are sqrt(4 - h)
+        let h = 1e-6;
+        let shifted_root = (4.0_f64 - h).sqrt();
+        assert_abs_diff_eq!((shifted_root - 2.0) / h, sensitivity[0], epsilon = 1e-6);
+    }
+
+    // Test partial fraction decomposition over known roots
+    #[test]
+    fn test_partial_fractions() {
+        // 1 / ((x - 1)(x - 2)) = -1 / (x - 1) + 1 / (x - 2)
+        let numerator = Polynomial::new(vec![1.0]);
+        let decomposition = partial_fractions(&numerator, &[1.0, 2.0]);
+        assert_eq!(decomposition, vec![(1.0, -1.0), (2.0, 1.0)]);
+
+        // The decomposition reproduces the rational function away from the poles
+        let x = 3.5;
+        let sum: f64 = decomposition.iter().map(|&(r, residue)| residue / (x - r)).sum();
+        assert_abs_diff_eq!(sum, 1.0 / ((x - 1.0) * (x - 2.0)), epsilon = 1e-12);
+    }
+
+    // Test polynomial pretty-printing edge cases
+    #[test]
+    fn test_format_polynomial() {
+        assert_eq!(format_polynomial(&[-6.0, 11.0, -6.0, 1.0]), "x^3 -6x^2 +11x -6");
+        assert_eq!(format_polynomial(&[4.0, 0.0, -1.0]), "-x^2 +4");
+        assert_eq!(format_polynomial(&[0.0, -1.0, 0.0, 2.5, 0.0]), "2.5x^3 -x");
+        assert_eq!(format_polynomial(&[5.0]), "5");
+        assert_eq!(format_polynomial(&[0.0, 0.0]), "0");
+        assert_eq!(Polynomial::new(vec![-4.0, 1.0, 1.0]).to_string(), "x^2 +x -4");
+    }
+
+    // Test dual-number evaluation against the separate value and derivative functions
+    #[test]
+    fn test_eval_dual() {
+        use crate::dual::eval_dual;
+        use crate::evaluate_derivative;
+
+        let coefficients = vec![-6.0, 11.0, -6.0, 1.0];
+        for &x in &[-1.5, 0.0, 0.5, 2.0, 3.25] {
+            let (value, derivative) = eval_dual(&coefficients, x);
+            assert_abs_diff_eq!(value, evaluate_polynomial(&coefficients, x), epsilon = 1e-12);
+            assert_abs_diff_eq!(derivative, evaluate_derivative(&coefficients, x), epsilon = 1e-12);
+        }
+    }
+
+    // Test that random restarts recover all real roots of the cubic
+    #[test]
+    fn test_find_roots_random_restart() {
+        let solver = HyperCatalanPolynomialSolver::new(3, 10);
+        let coefficients = vec![-6.0, 11.0, -6.0, 1.0];
+
+        let roots = solver.find_roots_random_restart(&coefficients, 50, 42);
+        assert_eq!(roots.len(), 3);
+        for (root, expected) in roots.iter().zip([1.0, 2.0, 3.0]) {
+            assert_abs_diff_eq!(*root, expected, epsilon = 1e-10);
+        }
+
+        // x^2 + 1 has no real roots to discover
+        assert!(solver.find_roots_random_restart(&[1.0, 0.0, 1.0], 20, 7).is_empty());
+    }
+
+    // Test Krawczyk verification of the root of x^2 - 2
+    #[test]
+    fn test_verify_root_krawczyk() {
+        use crate::verify::verify_root_krawczyk;
+
+        let coefficients = vec![-2.0, 0.0, 1.0];
+        assert!(verify_root_krawczyk(&coefficients, (1.41, 1.42)));
+        let sqrt2 = std::f64::consts::SQRT_2;
+        assert!(verify_root_krawczyk(&coefficients, (sqrt2 - 1e-9, sqrt2 + 1e-9)));
+
+        // No root in the interval, and an interval holding both roots, cannot be verified
+        assert!(!verify_root_krawczyk(&coefficients, (1.5, 1.6)));
+        assert!(!verify_root_krawczyk(&coefficients, (-2.0, 2.0)));
+    }
+
+    // Test that both coefficient orders describe the same polynomial
+    #[test]
+    fn test_coefficient_order() {
+        use crate::CoeffOrder;
+
+        // 2x^2 - 3x + 1 written both ways
+        let ascending = Polynomial::from_slice(&[1.0, -3.0, 2.0], CoeffOrder::Ascending);
+        let descending = Polynomial::from_slice(&[2.0, -3.0, 1.0], CoeffOrder::Descending);
+        assert_eq!(ascending, descending);
+        assert_eq!("desc".parse::<CoeffOrder>(), Ok(CoeffOrder::Descending));
+        assert!("sideways".parse::<CoeffOrder>().is_err());
+
+        let mut solver = HyperCatalanPolynomialSolver::new(2, 10);
+        assert_eq!(
+            solver.solve_polynomial(&ascending.coefficients),
+            solver.solve_polynomial(&descending.coefficients)
+        );
+        let roots_a = solver.find_roots_random_restart(&ascending.coefficients, 20, 1);
+        let roots_d = solver.find_roots_random_restart(&descending.coefficients, 20, 1);
+        assert_eq!(roots_a, roots_d);
+    }
+
+    // Test intersecting a line and a parabola via the resultant
+    #[test]
+    fn test_solve_two_univariate_via_resultant() {
+        use crate::bivariate::BivariatePolynomial;
+        use crate::system::{resultant, solve_system_via_resultant, solve_two_univariate_via_resultant};
+
+        // (x - 1)(x - 2) and (x - 2)(x - 3) share the root 2
+        assert_abs_diff_eq!(resultant(&[2.0, -3.0, 1.0], &[6.0, -5.0, 1.0]), 0.0, epsilon = 1e-12);
+        assert!(resultant(&[2.0, -3.0, 1.0], &[12.0, -7.0, 1.0]).abs() > 1.0);
+
+        // Line y = x + 1 and parabola y = x^2, with p[i][j] the coefficient of x^i y^j
+        let line = vec![vec![-1.0, 1.0], vec![-1.0]];
+        let parabola = vec![vec![0.0, 1.0], vec![0.0], vec![-1.0]];
+        let solutions = solve_two_univariate_via_resultant(&line, &parabola);
+
+        let sqrt5 = 5.0_f64.sqrt();
+        let expected = [((1.0 - sqrt5) / 2.0, (3.0 - sqrt5) / 2.0), ((1.0 + sqrt5) / 2.0, (3.0 + sqrt5) / 2.0)];
+        assert_eq!(solutions.len(), 2);
+        for ((x, y), (ex, ey)) in solutions.iter().zip(expected) {
+            assert_abs_diff_eq!(*x, ex, epsilon = 1e-9);
+            assert_abs_diff_eq!(*y, ey, epsilon = 1e-9);
+        }
+
+        // The same system as BivariatePolynomials; padding zeros do not raise the degree
+        let padded_line = BivariatePolynomial::new(vec![vec![-1.0, 1.0, 0.0], vec![-1.0], vec![]]);
+        assert_eq!(solve_system_via_resultant(&padded_line, &BivariatePolynomial::new(parabola)), solutions);
+    }
+
+    // Test evaluating and multiplying bivariate polynomials
+    #[test]
+    fn test_bivariate_polynomial() {
+        use crate::bivariate::BivariatePolynomial;
+
+        // p = 1 + 2y + 3x, q = x - y
+        let p = BivariatePolynomial::new(vec![vec![1.0, 2.0], vec![3.0]]);
+        let q = BivariatePolynomial::new(vec![vec![0.0, -1.0], vec![1.0]]);
+        assert_eq!((p.degree_x(), p.degree_y()), (1, 1));
+        assert_abs_diff_eq!(p.eval(2.0, -1.0), 5.0, epsilon = 1e-12);
+        assert_eq!(p.specialize_x(2.0), vec![7.0, 2.0]);
+
+        // p·q = x - y + 3x² - 2y² - xy (the 2xy and -3xy terms combine)
+        let product = &p * &q;
+        assert_eq!((product.degree_x(), product.degree_y()), (2, 2));
+        assert_abs_diff_eq!(product.coefficient(1, 0), 1.0);
+        assert_abs_diff_eq!(product.coefficient(0, 1), -1.0);
+        assert_abs_diff_eq!(product.coefficient(2, 0), 3.0);
+        assert_abs_diff_eq!(product.coefficient(0, 2), -2.0);
+        assert_abs_diff_eq!(product.coefficient(1, 1), -1.0);
+        for &(x, y) in &[(0.5, 1.5), (-2.0, 3.0)] {
+            assert_abs_diff_eq!(product.eval(x, y), p.eval(x, y) * q.eval(x, y), epsilon = 1e-12);
+        }
+    }
+
+    // Test the generating function matches the series summed by solve_polynomial
+    #[test]
+    fn test_evaluate_series() {
+        let coefficients = vec![1.0, 5.0, -0.2, 0.1];
+        let mut solver = HyperCatalanPolynomialSolver::for_coefficients(&coefficients, 8);
+        let history = solver.convergence_history(&coefficients).unwrap();
+        let internal = history.last().unwrap().1;
+
+        let t = crate::solver::to_geometric_form(&coefficients).unwrap();
+        assert_abs_diff_eq!(solver.evaluate_series(&t, 7), internal, epsilon = 1e-15);
+
+        // With no t_i the only type is the empty one, with C = 1
+        assert_abs_diff_eq!(solver.evaluate_series(&[1.0, -1.0], 5), 1.0);
+        assert_abs_diff_eq!(solver.evaluate_series(&[1.0], 5), 1.0);
+        assert_abs_diff_eq!(solver.evaluate_series(&[], 5), 1.0);
+    }
+
+    // Test evaluating polynomials at complex points
+    #[test]
+    fn test_evaluate_polynomial_complex() {
+        use crate::{evaluate_derivative, evaluate_derivative_complex, evaluate_polynomial_complex};
+        use num::Complex;
+
+        let i = Complex::new(0.0, 1.0);
+        let x_squared_plus_one: Vec<Complex<f64>> = [1.0, 0.0, 1.0].iter().map(|&c| Complex::new(c, 0.0)).collect();
+        assert_eq!(evaluate_polynomial_complex(&x_squared_plus_one, i), Complex::new(0.0, 0.0));
+        assert_eq!(evaluate_derivative_complex(&x_squared_plus_one, i), 2.0 * i);
+
+        // Real coefficients lifted to Complex agree with the real evaluation
+        let cubic: Vec<Complex<f64>> = [-6.0, 11.0, -6.0, 1.0].iter().map(|&c| Complex::new(c, 0.0)).collect();
+        let x = Complex::new(1.5, 0.0);
+        assert_abs_diff_eq!(evaluate_polynomial_complex(&cubic, x).re, evaluate_polynomial(&[-6.0, 11.0, -6.0, 1.0], 1.5), epsilon = 1e-12);
+        assert_abs_diff_eq!(evaluate_derivative_complex(&cubic, x).re, evaluate_derivative(&[-6.0, 11.0, -6.0, 1.0], 1.5), epsilon = 1e-12);
+    }
+
+    // Test Hyper-Catalan numbers come out as integers
+    #[test]
+    fn test_hyper_catalan_integer() {
+        let mut calculator = HyperCatalanCalculator::new();
+
+        // Two quadrilaterals subdivide a hexagon in 6! / (5! * 2!) = 3 ways
+        let two_quadrilaterals = SubdigonType::new(vec![0, 2, 0]);
+        assert_eq!(calculator.hyper_catalan_integer(&two_quadrilaterals), Some(3.into()));
+
+        // Triangle-only types give the Catalan numbers 1, 1, 2, 5, 14, 42
+        for (k, catalan) in [1, 1, 2, 5, 14, 42].into_iter().enumerate() {
+            let type_ = SubdigonType::new(vec![k as i32, 0, 0]);
+            assert_eq!(calculator.hyper_catalan_integer(&type_), Some(catalan.into()));
+        }
+    }
+
+    // Test solving a depressed cubic directly and via the depressing path
+    #[test]
+    fn test_solve_depressed() {
+        // x^3 - 7x + 6 = (x - 1)(x - 2)(x + 3) has no x^2 term
+        let depressed_cubic = vec![6.0, -7.0, 0.0, 1.0];
+        let mut solver = HyperCatalanPolynomialSolver::for_coefficients(&depressed_cubic, 10);
+        let seed = solver.solve_depressed(&depressed_cubic).unwrap();
+        let root = solver.newton_root(&depressed_cubic, seed, 50);
+        assert!([1.0, 2.0, -3.0].iter().any(|&r| (root - r).abs() < 1e-10));
+
+        // (x - 2)(x - 3)(x + 2) = x^3 - 3x^2 - 4x + 12 depresses to the same shape with
+        // shift 1, so the depressing path returns solve_depressed's root shifted by 1
+        let cubic = vec![12.0, -4.0, -3.0, 1.0];
+        let (depressed, shift) = depress(&cubic);
+        assert_abs_diff_eq!(shift, 1.0, epsilon = 1e-12);
+        let direct = solver.solve_depressed(&depressed).unwrap() + shift;
+        solver.set_depressing(true);
+        assert_eq!(solver.solve_polynomial(&cubic).unwrap(), direct);
+    }
+
+    // Test the streaming mode solves one polynomial per line
+    #[test]
+    fn test_run_stream() {
+        use crate::cli::{run_stream, OutputFormat};
+        use crate::CoeffOrder;
+
+        let input = "6, -5, 1\n\n-6,11,-6,1\nnot,a,polynomial\n-1, 1\n";
+        let mut output = Vec::new();
+        run_stream(input.as_bytes(), &mut output, CoeffOrder::Ascending, OutputFormat::Plain).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[2], "ERROR");
+        let quadratic_root: f64 = lines[0].parse().unwrap();
+        assert!([2.0, 3.0].iter().any(|&r| (quadratic_root - r).abs() < 1e-10));
+        let cubic_root: f64 = lines[1].parse().unwrap();
+        assert!([1.0, 2.0, 3.0].iter().any(|&r| (cubic_root - r).abs() < 1e-10));
+        assert_abs_diff_eq!(lines[3].parse::<f64>().unwrap(), 1.0, epsilon = 1e-12);
+    }
+
+    // Test the CSV batch output has a header, one row per polynomial and quoted inputs
+    #[test]
+    fn test_run_stream_csv() {
+        use crate::cli::{csv_escape, run_stream, OutputFormat, CSV_HEADER};
+        use crate::CoeffOrder;
+
+        assert_eq!(csv_escape("1.5"), "1.5");
+        assert_eq!(csv_escape("say \"hi\", twice"), "\"say \"\"hi\"\", twice\"");
+
+        let input = "6,-5,1\n\n-6, 11, -6, 1\nbad\n";
+        let mut output = Vec::new();
+        run_stream(input.as_bytes(), &mut output, CoeffOrder::Ascending, OutputFormat::Csv).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        let rows: Vec<&str> = output.lines().collect();
+        assert_eq!(rows.len(), 4);
+        assert_eq!(rows[0], CSV_HEADER);
+        assert!(rows[1].starts_with("\"6,-5,1\","));
+        assert!(rows[1].ends_with(",true"));
+        assert!(rows[2].starts_with("\"-6, 11, -6, 1\","));
+        assert_eq!(rows[3], "bad,,,false");
+    }
+
+    // Test a double root is reported once by solve_all_roots
+    #[test]
+    fn test_solve_all_roots_dedup() {
+        use crate::roots::{count_distinct_roots, dedup_roots};
+
+        let mut roots = vec![3.0, 2.0 + 1e-9, 2.0 - 1e-9, 2.0];
+        assert_eq!(count_distinct_roots(&roots, 1e-6), 2);
+        dedup_roots(&mut roots, 1e-6);
+        assert_eq!(roots.len(), 2);
+        assert_abs_diff_eq!(roots[0], 2.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(roots[1], 3.0);
+
+        // (x - 2)^2 (x - 3) = x^3 - 7x^2 + 16x - 12
+        let coefficients = vec![-12.0, 16.0, -7.0, 1.0];
+        let mut solver = HyperCatalanPolynomialSolver::for_coefficients(&coefficients, 10);
+        let roots = solver.solve_all_roots(&coefficients);
+        assert_eq!(roots.len(), 2);
+        assert_abs_diff_eq!(roots[0], 2.0, epsilon = 1e-6);
+        assert_abs_diff_eq!(roots[1], 3.0, epsilon = 1e-10);
+
+        // x^2 + 1 has no real roots
+        assert!(solver.solve_all_roots(&[1.0, 0.0, 1.0]).is_empty());
+    }
+
+    // Test a Laurent polynomial is solved by clearing its negative powers
+    #[test]
+    fn test_laurent_polynomial() {
+        use crate::laurent::LaurentPolynomial;
+
+        // x^{-1} - 2.5 + x
+        let laurent = LaurentPolynomial::new(-1, vec![1.0, -2.5, 1.0]);
+        assert_abs_diff_eq!(laurent.eval(2.0).unwrap(), 0.0);
+        assert_abs_diff_eq!(laurent.eval(1.0).unwrap(), -0.5);
+        assert_eq!(laurent.eval(0.0), None);
+
+        let polynomial = laurent.to_polynomial();
+        assert_eq!(polynomial.coefficients, vec![1.0, -2.5, 1.0]);
+
+        let mut solver = HyperCatalanPolynomialSolver::for_coefficients(&polynomial.coefficients, 10);
+        let roots = solver.solve_all_roots(&polynomial.coefficients);
+        assert_eq!(roots.len(), 2);
+        assert_abs_diff_eq!(roots[0], 0.5, epsilon = 1e-12);
+        assert_abs_diff_eq!(roots[1], 2.0, epsilon = 1e-12);
+        for root in roots {
+            assert_abs_diff_eq!(laurent.eval(root).unwrap(), 0.0, epsilon = 1e-12);
+        }
+    }
+
+    // Test evaluating a rational function with a removable singularity
+    #[test]
+    fn test_evaluate_rational() {
+        use crate::polynomial::evaluate_rational;
+
+        // (x^2 - 1) / (x - 1) = x + 1 away from x = 1
+        let numerator = [-1.0, 0.0, 1.0];
+        let denominator = [-1.0, 1.0];
+        for x in [-3.0, 0.0, 0.5, 2.0, 10.0] {
+            assert_abs_diff_eq!(evaluate_rational(&numerator, &denominator, x).unwrap(), x + 1.0, epsilon = 1e-12);
+        }
+        assert_eq!(evaluate_rational(&numerator, &denominator, 1.0), None);
+        assert_eq!(evaluate_rational(&[1.0], &[0.0], 2.0), None);
+    }
+
+    // Test Richardson extrapolation beats the raw partial sum at equal term budget
+    #[test]
+    fn test_richardson_extrapolate() {
+        use crate::acceleration::richardson_extrapolate;
+
+        // Σ 1/k² converges to π²/6 with error ~ 1/n
+        let limit = std::f64::consts::PI.powi(2) / 6.0;
+        let partial_sums: Vec<f64> = (1..=20)
+            .scan(0.0, |sum, k| {
+                *sum += 1.0 / (k * k) as f64;
+                Some(*sum)
+            })
+            .collect();
+
+        let raw_error = (partial_sums.last().unwrap() - limit).abs();
+        let extrapolated_error = (richardson_extrapolate(&partial_sums, 4) - limit).abs();
+        assert!(raw_error > 1e-2);
+        assert!(extrapolated_error < 1e-6, "error {}", extrapolated_error);
+
+        // Order zero is the last partial sum itself
+        assert_eq!(richardson_extrapolate(&partial_sums, 0), partial_sums[19]);
+    }
+
+    // Test the acceleration strategies improve a slowly converging series
+    #[test]
+    fn test_acceleration_strategies() {
+        use crate::acceleration::{aitken_extrapolate, shanks_extrapolate, Acceleration};
+
+        // Aitken and Shanks(k) are exact when the error is a sum of 1 and k geometric terms
+        let geometric: Vec<f64> = (0..7).map(|n| 3.0 + 0.5_f64.powi(n)).collect();
+        assert_abs_diff_eq!(aitken_extrapolate(&geometric), 3.0, epsilon = 1e-12);
+        let two_geometric: Vec<f64> = (0..7).map(|n| 3.0 + 0.5_f64.powi(n) - 0.2_f64.powi(n)).collect();
+        assert_abs_diff_eq!(shanks_extrapolate(&two_geometric, 2), 3.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(shanks_extrapolate(&geometric, 2), 3.0, epsilon = 1e-12);
+
+        // 1 + x + 0.24x^2 sits close to the series' radius of convergence, 1/4
+        let coefficients = vec![1.0, 1.0, 0.24];
+        let mut reference = HyperCatalanPolynomialSolver::for_coefficients(&coefficients, 400);
+        let limit = reference.solve_polynomial(&coefficients).unwrap();
+
+        // The raw 15-level sum is too rough to pass the default residual check
+        let mut solver = HyperCatalanPolynomialSolver::for_coefficients(&coefficients, 15);
+        solver.set_residual_tolerance(None);
+        let raw_error = (solver.solve_polynomial(&coefficients).unwrap() - limit).abs();
+        for acceleration in [Acceleration::Aitken, Acceleration::Shanks(3), Acceleration::Richardson(4)] {
+            solver.set_acceleration(acceleration);
+            let error = (solver.solve_polynomial(&coefficients).unwrap() - limit).abs();
+            assert!(error < raw_error / 2.0, "{:?}: {} vs {}", acceleration, error, raw_error);
+        }
+
+        solver.set_acceleration(Acceleration::None);
+        assert_abs_diff_eq!((solver.solve_polynomial(&coefficients).unwrap() - limit).abs(), raw_error);
+    }
+
+    // Test the geometric-form conversion and that the series now lands on a root
+    #[test]
+    fn test_to_geometric_form() {
+        use crate::solver::to_geometric_form;
+        use crate::SolverError;
+
+        // x = -c0·a/c1 = -a/5 turns 1 + 5x - 0.2x^2 + 0.1x^3 into 1 - a - 0.008a^2 - 0.0008a^3
+        let coefficients = vec![1.0, 5.0, -0.2, 0.1];
+        let t = to_geometric_form(&coefficients).unwrap();
+        assert_eq!(t.len(), 4);
+        assert_eq!((t[0], t[1]), (1.0, -1.0));
+        assert_abs_diff_eq!(t[2], -0.008, epsilon = 1e-15);
+        assert_abs_diff_eq!(t[3], -0.0008, epsilon = 1e-15);
+
+        let mut solver = HyperCatalanPolynomialSolver::for_coefficients(&coefficients, 10);
+        let root = solver.solve_polynomial(&coefficients).unwrap();
+        assert_abs_diff_eq!(evaluate_polynomial(&coefficients, root), 0.0, epsilon = 1e-12);
+
+        // A solver sized for higher degrees gives the same root
+        let mut roomy = HyperCatalanPolynomialSolver::new(5, 10);
+        assert_abs_diff_eq!(roomy.solve_polynomial(&coefficients).unwrap(), root, epsilon = 1e-15);
+
+        assert_eq!(to_geometric_form(&[1.0, 0.0, 1.0]), Err(SolverError::ZeroLinearCoefficient));
+    }
+
+    // Test mapping geometric-form roots back to the original variable
+    #[test]
+    fn test_from_geometric_root() {
+        use crate::solver::{from_geometric_root, to_geometric_form};
+        use crate::SolverError;
+
+        // 6 - 5x + x^2 has roots 2 and 3; each maps to a root of its geometric form
+        let coefficients = vec![6.0, -5.0, 1.0];
+        let t = to_geometric_form(&coefficients).unwrap();
+        for x in [2.0, 3.0] {
+            let a = -coefficients[1] * x / coefficients[0];
+            assert_abs_diff_eq!(evaluate_polynomial(&t, a), 0.0, epsilon = 1e-12);
+            assert_abs_diff_eq!(from_geometric_root(&coefficients, a).unwrap(), x, epsilon = 1e-12);
+        }
+
+        assert_eq!(from_geometric_root(&coefficients, 0.0), Err(SolverError::ZeroGeometricRoot));
+        assert_eq!(from_geometric_root(&[1.0, 0.0, 1.0], 1.0), Err(SolverError::ZeroLinearCoefficient));
+    }
+
+    // Cross-check the factorial formula against the functional-equation recurrence
+    mod recurrence {
+        use crate::subdigon::SubdigonTypes;
+        use crate::HyperCatalanCalculator;
+        use num::BigRational;
+
+        #[test]
+        fn test_calculate_matches_recurrence() {
+            let mut calculator = HyperCatalanCalculator::new();
+            for total_faces in 0..=6 {
+                for type_ in SubdigonTypes::new(total_faces, 3) {
+                    let recursive = calculator.calculate_recursive(&type_);
+                    assert_eq!(calculator.calculate(&type_), BigRational::from(recursive), "C_{}", type_);
+                }
+            }
+        }
+    }
+
+    // Test the exact series value agrees with the floating-point series
+    #[test]
+    fn test_solve_polynomial_exact() {
+        use num::{BigRational, ToPrimitive};
+
+        // 1 + 5x - 2x^2 + x^3 with integer coefficients
+        let coefficients = vec![1.0, 5.0, -2.0, 1.0];
+        let exact: Vec<BigRational> = [1, 5, -2, 1].iter().map(|&c| BigRational::from_integer(c.into())).collect();
+
+        let mut solver = HyperCatalanPolynomialSolver::for_coefficients(&coefficients, 8);
+        let report = solver.solve_polynomial_exact(&exact).unwrap();
+        let history = solver.convergence_history(&coefficients).unwrap();
+        let (_, float_series, float_root) = *history.last().unwrap();
+
+        let series_value = report.series_value_exact.unwrap();
+        assert_abs_diff_eq!(series_value.to_f64().unwrap(), float_series, epsilon = 1e-14);
+        assert_abs_diff_eq!(report.root, float_root, epsilon = 1e-14);
+        assert!(report.residual < 1e-3);
+
+        // The float report has no exact value
+        assert_eq!(solver.solve_polynomial_report(&coefficients).unwrap().series_value_exact, None);
+    }
+
+    // Test Newton's method polishes the series root
+    #[test]
+    fn test_solve_compare() {
+        // x^5 - x - 1 has t₅ = 1 in geometric form, far outside the series' radius of
+        // convergence, so only a few face levels give a usable seed
+        let coefficients = vec![-1.0, -1.0, 0.0, 0.0, 0.0, 1.0];
+        let mut solver = HyperCatalanPolynomialSolver::for_coefficients(&coefficients, 3);
+        let (series_root, series_residual, newton_root, newton_residual) =
+            solver.solve_compare(&coefficients, 50).unwrap();
+
+        assert_eq!(series_root, solver.convergence_history(&coefficients).unwrap()[2].2);
+        assert_abs_diff_eq!(series_residual, evaluate_polynomial(&coefficients, series_root).abs());
+        assert_abs_diff_eq!(newton_root, 1.1673039782614187, epsilon = 1e-12);
+        assert!(newton_residual < 1e-12);
+        assert!(newton_residual < series_residual * 1e-6);
+    }
+
+    // Test a series summed outside its radius of convergence is rejected
+    #[test]
+    fn test_residual_check_rejects_divergent_series() {
+        use crate::SolverError;
+
+        // x^5 - x - 1 has t₅ = 1 in geometric form, so the series diverges
+        let coefficients = vec![-1.0, -1.0, 0.0, 0.0, 0.0, 1.0];
+        let mut solver = HyperCatalanPolynomialSolver::for_coefficients(&coefficients, 10);
+        let root = solver.convergence_history(&coefficients).unwrap().last().unwrap().2;
+        let residual = evaluate_polynomial(&coefficients, root).abs();
+        assert_eq!(
+            solver.solve_polynomial(&coefficients),
+            Err(SolverError::NotConverged { residual: Some(residual) })
+        );
+
+        solver.set_residual_tolerance(None);
+        assert_eq!(solver.solve_polynomial(&coefficients), Ok(root));
+
+        // A convergent series passes the default check
+        let convergent = vec![1.0, 5.0, -0.2, 0.1];
+        let mut solver = HyperCatalanPolynomialSolver::for_coefficients(&convergent, 10);
+        assert!(solver.solve_polynomial(&convergent).is_ok());
+    }
+
+    // Test the relative step criterion stops Newton's method at a large root
+    #[test]
+    fn test_relative_newton_tolerance() {
+        // x^2 - 2e16: near the root √2·1e8 the float spacing (~3e-8) dwarfs an absolute
+        // 1e-15, and the rounded f(x) never reaches zero
+        let coefficients = vec![-2e16, 0.0, 1.0];
+        let mut solver = HyperCatalanPolynomialSolver::new(2, 10);
+
+        let absolute = solver.bootstrap_root_history(&coefficients, 1.5e8, 100, 1e-15);
+        assert_eq!(absolute.len(), 101, "absolute criterion should use every iteration");
+
+        solver.set_relative_tolerance(true);
+        let relative = solver.bootstrap_root_history(&coefficients, 1.5e8, 100, 1e-15);
+        assert!(relative.len() < 50);
+        assert_abs_diff_eq!(relative.last().unwrap().0, 2.0_f64.sqrt() * 1e8, epsilon = 1e-6);
+    }
+
+    // Test reducing a palindromic quartic to a quadratic in x + 1/x
+    #[test]
+    fn test_reduce_reciprocal() {
+        use crate::polynomial::{is_palindromic, reciprocal_roots, reduce_reciprocal};
+
+        let quartic = vec![1.0, -5.0, 6.0, -5.0, 1.0];
+        assert!(is_palindromic(&quartic));
+        assert!(!is_palindromic(&[1.0, 2.0, 3.0]));
+        assert_eq!(reduce_reciprocal(&[1.0, 2.0, 2.0, 1.0]), None);
+
+        // y^2 - 5y + 4 = (y - 1)(y - 4)
+        assert_eq!(reduce_reciprocal(&quartic), Some(vec![4.0, -5.0, 1.0]));
+
+        // Sorted by magnitude: 2 - √3, then (1 ∓ i√3)/2 on the unit circle, then 2 + √3
+        let roots = reciprocal_roots(&quartic).unwrap();
+        assert_eq!(roots.len(), 4);
+        for i in 0..2 {
+            let product = roots[i] * roots[3 - i];
+            assert_abs_diff_eq!(product.re, 1.0, epsilon = 1e-12);
+            assert_abs_diff_eq!(product.im, 0.0, epsilon = 1e-12);
+        }
+        assert!(roots[1].im < 0.0 && roots[2].im > 0.0);
+        for root in &roots {
+            let value = quartic.iter().rev().fold(num::Complex::new(0.0, 0.0), |acc, &c| acc * root + c);
+            assert!(value.norm() < 1e-12);
+        }
+        let sqrt3 = 3.0_f64.sqrt();
+        assert_abs_diff_eq!(roots[0].re, 2.0 - sqrt3, epsilon = 1e-12);
+        assert_abs_diff_eq!(roots[3].re, 2.0 + sqrt3, epsilon = 1e-12);
+    }
+
+    // Test interactive prompts retry after a parse failure
+    #[test]
+    fn test_prompt_parse_retries() {
+        use crate::cli::prompt_parse;
+
+        let mut input = "oops\n7\n".as_bytes();
+        let mut output = Vec::new();
+        let value: Option<usize> = prompt_parse(&mut input, &mut output, "degree: ", None, 3).unwrap();
+        assert_eq!(value, Some(7));
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(output.matches("degree: ").count(), 2);
+        assert!(output.contains("Invalid input 'oops'"));
+
+        // Empty input takes the default; repeated failures and end of input give None
+        let mut output = Vec::new();
+        assert_eq!(prompt_parse(&mut "\n".as_bytes(), &mut output, "x: ", Some(1.5), 3).unwrap(), Some(1.5));
+        assert_eq!(prompt_parse::<f64, _, _>(&mut "a\nb\n".as_bytes(), &mut output, "x: ", None, 2).unwrap(), None);
+        assert_eq!(prompt_parse::<f64, _, _>(&mut "".as_bytes(), &mut output, "x: ", None, 3).unwrap(), None);
+    }
+
+    // Test every root of the cubic comes with a small residual
+    #[test]
+    fn test_solve_all_roots_with_residuals() {
+        let coefficients = vec![-6.0, 11.0, -6.0, 1.0];
+        let mut solver = HyperCatalanPolynomialSolver::for_coefficients(&coefficients, 10);
+        let roots = solver.solve_all_roots_with_residuals(&coefficients);
+
+        assert_eq!(roots.len(), 3);
+        for ((root, residual), expected) in roots.into_iter().zip([1.0, 2.0, 3.0]) {
+            assert_abs_diff_eq!(root, expected, epsilon = 1e-10);
+            assert!(residual < 1e-10);
+            assert_eq!(residual, evaluate_polynomial(&coefficients, root).abs());
+        }
+    }
+
+    // Test every registered root finder agrees on x^2 - 4
+    #[test]
+    fn test_root_finders_agree() {
+        use crate::finder::{DurandKernerFinder, HomotopyFinder, LaguerreFinder, RandomRestartFinder, RootFinder};
+
+        let finders: Vec<Box<dyn RootFinder>> = vec![
+            Box::new(HyperCatalanPolynomialSolver::new(2, 10)),
+            Box::new(LaguerreFinder),
+            Box::new(HomotopyFinder { steps: 100 }),
+            Box::new(RandomRestartFinder { attempts: 20, seed: 7 }),
+            Box::new(DurandKernerFinder::default()),
+        ];
+
+        let coefficients = [-4.0, 0.0, 1.0];
+        for finder in &finders {
+            let mut roots = finder.find_roots(&coefficients);
+            roots.sort_by(|a, b| a.re.total_cmp(&b.re));
+            assert_eq!(roots.len(), 2, "{}", finder.name());
+            for (root, expected) in roots.iter().zip([-2.0, 2.0]) {
+                assert_abs_diff_eq!(root.re, expected, epsilon = 1e-10);
+                assert_abs_diff_eq!(root.im, 0.0, epsilon = 1e-10);
+            }
+        }
+    }
+
+    // Test the robust solver uses the series when it converges and falls back otherwise
+    #[test]
+    fn test_solve_robust() {
+        use crate::solver::RobustPath;
+        use num::Complex;
+
+        let residual = |coefficients: &[f64], x: Complex<f64>| {
+            coefficients.iter().rev().fold(Complex::new(0.0, 0.0), |acc, &c| acc * x + c).norm()
+        };
+
+        // 1 + 5x - 0.2x^2 + 0.1x^3 is well inside the series' radius of convergence
+        let convergent = vec![1.0, 5.0, -0.2, 0.1];
+        let mut solver = HyperCatalanPolynomialSolver::for_coefficients(&convergent, 10);
+        let (roots, path) = solver.solve_robust_with_path(&convergent);
+        assert_eq!(path, RobustPath::Series);
+        assert_eq!(roots.len(), 3);
+        assert!(roots.iter().all(|&root| residual(&convergent, root) < 1e-10));
+
+        // x^5 - x - 1 makes the series diverge
+        let divergent = vec![-1.0, -1.0, 0.0, 0.0, 0.0, 1.0];
+        let mut solver = HyperCatalanPolynomialSolver::for_coefficients(&divergent, 10);
+        let (roots, path) = solver.solve_robust_with_path(&divergent);
+        assert_eq!(path, RobustPath::DurandKerner);
+        assert_eq!(roots.len(), 5);
+        assert!(roots.iter().all(|&root| residual(&divergent, root) < 1e-10));
+        assert_eq!(solver.solve_robust(&divergent), roots);
+
+        // x^4 - 5x^3 + 6x^2 - 5x + 1 is palindromic and solved in y = x + 1/x
+        let palindromic = vec![1.0, -5.0, 6.0, -5.0, 1.0];
+        let mut solver = HyperCatalanPolynomialSolver::for_coefficients(&palindromic, 10);
+        let (roots, path) = solver.solve_robust_with_path(&palindromic);
+        assert_eq!(path, RobustPath::Reciprocal);
+        assert_eq!(roots.len(), 4);
+        assert!(roots.iter().all(|&root| residual(&palindromic, root) < 1e-10));
+
+        // x^4 - 5x^2 + 4 is even and solved in u = x^2
+        let even = vec![4.0, 0.0, -5.0, 0.0, 1.0];
+        let mut solver = HyperCatalanPolynomialSolver::for_coefficients(&even, 10);
+        let (roots, path) = solver.solve_robust_with_path(&even);
+        assert_eq!(path, RobustPath::Parity);
+        assert_eq!(roots.len(), 4);
+        assert!(roots.iter().all(|&root| residual(&even, root) < 1e-10));
+    }
+
+    // Test topology agrees with the individual counts and rejects odd side totals
+    #[test]
+    fn test_subdigon_topology() {
+        use crate::subdigon::Topology;
+
+        for values in [vec![1, 0, 0], vec![2, 0, 0], vec![0, 2, 0], vec![2, 0, 1], vec![1, 2, 3]] {
+            let type_ = SubdigonType::new(values);
+            let topology = type_.topology().unwrap();
+            assert_eq!(topology, Topology {
+                faces: type_.faces() as usize,
+                edges: type_.edges() as usize,
+                vertices: type_.vertices() as usize,
+            });
+        }
+
+        // One triangle has 3 sides, which cannot pair up into edges
+        assert!(SubdigonType::new(vec![0, 1, 0]).topology().is_err());
+        assert!(SubdigonType::new(vec![-1, 0, 0]).topology().is_err());
+    }
+
+    // Test the empirical radius of convergence against the Catalan case and a cubic
+    #[test]
+    fn test_estimate_convergence_radius() {
+        let mut solver = HyperCatalanPolynomialSolver::new(3, 10);
+
+        // 1 - a + t a^2 is the Catalan series, which converges for |t| < 1/4
+        let radius = solver.estimate_convergence_radius(&[1.0, -1.0, 0.05], 40);
+        assert!((radius - 5.0).abs() < 0.5, "radius {}", radius);
+
+        // The cubic used throughout the tests has small t, so it has plenty of room
+        let coefficients = vec![1.0, 5.0, -0.2, 0.1];
+        let t = crate::solver::to_geometric_form(&coefficients).unwrap();
+        assert!(solver.estimate_convergence_radius(&t, 20) > 10.0);
+
+        // No t at all: only the empty type, so nothing can diverge
+        assert_eq!(solver.estimate_convergence_radius(&[1.0, -1.0], 10), f64::INFINITY);
+        assert_eq!(solver.estimate_convergence_radius(&[1.0], 10), f64::INFINITY);
+        assert_eq!(solver.estimate_convergence_radius(&[], 10), f64::INFINITY);
+    }
+
+    // Test the geometric-form polynomial vanishes at the series value
+    #[test]
+    fn test_geometric_form_polynomial() {
+        use crate::solver::{geometric_form_polynomial, to_geometric_form};
+
+        let coefficients = vec![1.0, 5.0, -0.2, 0.1];
+        let geometric = geometric_form_polynomial(&coefficients).unwrap();
+        assert_eq!(geometric.coefficients, to_geometric_form(&coefficients).unwrap());
+        assert_eq!(geometric.degree(), 3);
+
+        let mut solver = HyperCatalanPolynomialSolver::for_coefficients(&coefficients, 15);
+        let a = solver.convergence_history(&coefficients).unwrap().last().unwrap().1;
+        assert_abs_diff_eq!(geometric.evaluate(a), 0.0, epsilon = 1e-12);
+    }
+
+    // Test Newton's method detects the classic 2-cycle of x^3 - 2x + 2 from 0
+    #[test]
+    fn test_newton_cycle_detection() {
+        use crate::SolverError;
+
+        let coefficients = vec![2.0, -2.0, 0.0, 1.0];
+        let solver = HyperCatalanPolynomialSolver::new(3, 10);
+
+        // 0 -> 1 -> 0 is caught after two steps instead of running all 100
+        let history = solver.bootstrap_root_history(&coefficients, 0.0, 100, 1e-15);
+        assert_eq!(history.iter().map(|&(x, _)| x).collect::<Vec<_>>(), vec![0.0, 1.0, 0.0]);
+        assert_eq!(
+            solver.try_bootstrap_root(&coefficients, 0.0, 100, 1e-15),
+            Err(SolverError::NewtonCycle { x: 0.0 })
+        );
+
+        // A different start converges to the real root near -1.7693
+        let root = solver.try_bootstrap_root(&coefficients, -2.0, 100, 1e-15).unwrap();
+        assert_abs_diff_eq!(evaluate_polynomial(&coefficients, root), 0.0, epsilon = 1e-12);
+    }
+
+    // Test Taylor coefficients give p(r + h) as a polynomial in h, with p(r) and p'(r) first
+    #[test]
+    fn test_taylor_coefficients() {
+        use crate::evaluate_derivative;
+        use crate::polynomial::taylor_coefficients;
+
+        let coefficients = vec![-6.0, 11.0, -6.0, 1.0, 0.5];
+        let r = 1.5;
+        let taylor = taylor_coefficients(&coefficients, r);
+
+        assert_abs_diff_eq!(taylor[0], evaluate_polynomial(&coefficients, r), epsilon = 1e-12);
+        assert_abs_diff_eq!(taylor[1], evaluate_derivative(&coefficients, r), epsilon = 1e-12);
+        for &h in &[-2.0, -0.3, 0.0, 0.7, 3.0] {
+            assert_abs_diff_eq!(
+                evaluate_polynomial(&taylor, h),
+                evaluate_polynomial(&coefficients, r + h),
+                epsilon = 1e-9
+            );
+        }
+    }
+
+    // Test the polygon sizes enumerated default to max_degree - 1 and can be overridden
+    #[test]
+    fn test_max_polygon_size() {
+        let t = vec![1.0, -1.0, 0.1, 0.05];
+        let mut solver = HyperCatalanPolynomialSolver::new(3, 3);
+        assert_eq!(solver.max_polygon_size(), 2);
+
+        // Face levels 0, 1 and 2 over two polygon sizes hold 1 + 2 + 3 types
+        assert_eq!(solver.series_term_contributions(&t).len(), 6);
+
+        // Only triangles: one type per face level, and the t₃ term is gone
+        solver.set_max_polygon_size(Some(1));
+        assert_eq!(solver.max_polygon_size(), 1);
+        let contributions = solver.series_term_contributions(&t);
+        assert_eq!(contributions.len(), 3);
+        assert!(contributions.iter().all(|(type_, _)| type_.m.len() == 1));
+
+        solver.set_max_polygon_size(None);
+        assert_eq!(solver.max_polygon_size(), 2);
+    }
+
+    // Test the exact Sturm count on simple, multiple and endpoint roots
+    #[test]
+    fn test_count_real_roots_exact() {
+        use crate::rational::count_real_roots_exact;
+
+        // (x - 1)(x - 2)(x - 3)
+        let cubic = [-6, 11, -6, 1];
+        assert_eq!(count_real_roots_exact(&cubic, 0, 4), 3);
+        assert_eq!(count_real_roots_exact(&cubic, 1, 2), 1);
+        assert_eq!(count_real_roots_exact(&cubic, 4, 0), 0);
+
+        // (x - 1)²(x + 2): the double root counts once
+        assert_eq!(count_real_roots_exact(&[2, -3, 0, 1], -3, 3), 2);
+
+        // x² + 1 has no real roots
+        assert_eq!(count_real_roots_exact(&[1, 0, 1], -10, 10), 0);
+
+        // (x - 1)²(x - 3) with the double root at an endpoint: excluded at a, included at b
+        let double = [-3, 7, -5, 1];
+        assert_eq!(count_real_roots_exact(&double, 1, 2), 0);
+        assert_eq!(count_real_roots_exact(&double, 1, 4), 1);
+        assert_eq!(count_real_roots_exact(&double, 0, 1), 1);
+        assert_eq!(count_real_roots_exact(&double, 0, 3), 2);
+    }
+
+    // Test reset empties the cache but keeps the solver usable with the same settings
+    #[test]
+    fn test_solver_reset() {
+        let coefficients = vec![1.0, -4.0, 1.0];
+        let mut solver = HyperCatalanPolynomialSolver::new(2, 10);
+        let root = solver.solve_polynomial(&coefficients).unwrap();
+        assert!(solver.calculator().cache_size() > 0);
+
+        solver.reset();
+        assert_eq!(solver.calculator().cache_size(), 0);
+        assert_eq!(solver.solve_polynomial(&coefficients).unwrap(), root);
+    }
+
+    // Test the symbolic series has one term per subdigon type, with exact coefficients
+    #[test]
+    fn test_series_symbolic() {
+        use crate::subdigon::generate_types;
+
+        let mut solver = HyperCatalanPolynomialSolver::new(3, 10);
+        let terms = solver.series_symbolic(3, 2);
+
+        let type_count: usize = (0..=3).map(|faces| generate_types(faces, 2).len()).sum();
+        assert_eq!(terms.len(), type_count);
+        assert_eq!(terms[0], "[C_(0,0) = 1]");
+        assert!(terms.contains(&"[C_(2,1) = 21] * t_2^2 * t_3".to_string()));
+        assert!(terms.contains(&"[C_(0,1) = 1] * t_3".to_string()));
+    }
+
+    // Test solve_equals solves p(x) = k without touching the coefficients
+    #[test]
+    fn test_solve_equals() {
+        use crate::SolverError;
+
+        let mut solver = HyperCatalanPolynomialSolver::new(2, 20);
+
+        // x² - 4x = -1 has roots 2 ± √3; the series finds the smaller one
+        let coefficients = vec![0.0, -4.0, 1.0];
+        let root = solver.solve_equals(&coefficients, -1.0).unwrap();
+        assert_abs_diff_eq!(root, 2.0 - 3.0f64.sqrt(), epsilon = 1e-6);
+        assert_eq!(coefficients, vec![0.0, -4.0, 1.0]);
+
+        // x² = 4 has no linear term for the series, but its integer roots can be found exactly
+        assert_eq!(solver.solve_equals(&[0.0, 0.0, 1.0], 4.0), Err(SolverError::ZeroLinearCoefficient));
+        solver.set_rational_shortcut(true);
+        assert_eq!(solver.solve_equals(&[0.0, 0.0, 1.0], 4.0).unwrap().abs(), 2.0);
+        assert_eq!(solver.solve_equals(&[0.0, 0.0, 1.0], 2.0), Err(SolverError::ZeroLinearCoefficient));
+    }
+
+    // Test exact rational Newton refines √2 from 3/2 through the convergents 17/12, 577/408, ...
+    #[test]
+    fn test_newton_root_exact() {
+        use num::{BigInt, BigRational, ToPrimitive};
+
+        let rational = |n: i64, d: i64| BigRational::new(BigInt::from(n), BigInt::from(d));
+        let coefficients = vec![rational(-2, 1), rational(0, 1), rational(1, 1)];
+        let solver = HyperCatalanPolynomialSolver::new(2, 10);
+
+        assert_eq!(solver.newton_root_exact(&coefficients, rational(3, 2), 1), rational(17, 12));
+        assert_eq!(solver.newton_root_exact(&coefficients, rational(3, 2), 2), rational(577, 408));
+
+        let root = solver.newton_root_exact(&coefficients, rational(3, 2), 4);
+        let error = (&root * &root - rational(2, 1)).to_f64().unwrap();
+        assert!(error > 0.0 && error < 1e-20);
+    }
+
+    // Test integer roots of an integer polynomial come back exactly when the shortcut is on
+    #[test]
+    fn test_solve_polynomial_rational_roots() {
+        // (x - 1)(x - 2)(x - 3): the truncated series alone only lands near 1
+        let cubic = vec![-6.0, 11.0, -6.0, 1.0];
+        let mut solver = HyperCatalanPolynomialSolver::new(3, 10);
+        solver.set_residual_tolerance(None);
+        let series = solver.solve_polynomial(&cubic).unwrap();
+        assert_ne!(series, 1.0);
+
+        solver.set_rational_shortcut(true);
+        let root = solver.solve_polynomial(&cubic).unwrap();
+        assert_eq!(root, 1.0);
+        assert_eq!(evaluate_polynomial(&cubic, root), 0.0);
+
+        // (2x - 1)(x + 4) has the rational root 1/2
+        assert_eq!(solver.solve_polynomial(&[-4.0, 7.0, 2.0]).unwrap(), 0.5);
+
+        // Coefficients past 1e6 skip the divisor search and go to the series
+        let large = [735134400.0, 1.0, 0.0, 0.0, 0.0, 735134400.0];
+        let mut solver = HyperCatalanPolynomialSolver::new(5, 10);
+        solver.set_rational_shortcut(true);
+        solver.set_residual_tolerance(None);
+        assert!(solver.solve_polynomial(&large).is_ok());
+        assert!(solver.solve_polynomial_report(&large).unwrap().dominant_type.is_some());
+    }
+
+    // Test NaN and infinite coefficients are rejected instead of propagating
+    #[test]
+    fn test_invalid_coefficient() {
+        use crate::SolverError;
+
+        let mut solver = HyperCatalanPolynomialSolver::new(3, 10);
+        assert_eq!(
+            solver.solve_polynomial(&[1.0, f64::NAN, 1.0]),
+            Err(SolverError::InvalidCoefficient { index: 1 })
+        );
+        assert_eq!(
+            solver.solve_polynomial_report(&[1.0, -4.0, f64::INFINITY]),
+            Err(SolverError::InvalidCoefficient { index: 2 })
+        );
+        assert_eq!(
+            SolverError::InvalidCoefficient { index: 1 }.to_string(),
+            "Coefficient c1 is not a finite number"
+        );
+    }
+
+    // Test scalar multiples share one memoized series sum
+    #[test]
+    fn test_series_memoization() {
+        let coefficients = vec![1.0, -4.0, 1.5];
+        let scaled: Vec<f64> = coefficients.iter().map(|c| c * 2.5).collect();
+
+        let mut solver = HyperCatalanPolynomialSolver::new(2, 15);
+        solver.set_series_memoization(Some(1e-12));
+        let root = solver.solve_polynomial(&coefficients).unwrap();
+        assert_eq!(solver.series_memo_hits(), 0);
+        assert_eq!(solver.solve_polynomial(&scaled).unwrap(), root);
+        assert_eq!(solver.series_memo_hits(), 1);
+
+        // A different shape misses, and reset clears the hit count
+        solver.solve_polynomial(&[1.0, -4.0, 1.2]).unwrap();
+        assert_eq!(solver.series_memo_hits(), 1);
+        solver.reset();
+        assert_eq!(solver.series_memo_hits(), 0);
+    }
+
+    // Test the uncached formula agrees with the calculator's cached values
+    #[test]
+    fn test_hyper_catalan_number_pure() {
+        use crate::calculator::hyper_catalan_number;
+        use crate::subdigon::SubdigonTypes;
+
+        let mut calculator = HyperCatalanCalculator::new();
+        for total_faces in 0..6 {
+            for type_ in SubdigonTypes::new(total_faces, 3) {
+                assert_eq!(hyper_catalan_number(&type_), calculator.calculate(&type_));
+                // The cached path returns the same value on a second lookup
+                assert_eq!(hyper_catalan_number(&type_), calculator.calculate(&type_));
+            }
+        }
+    }
+
+    // Test the accuracy bound is zero for an exact root and tracks the error of a perturbed one
+    #[test]
+    fn test_root_float_error_bound() {
+        use crate::report::{root_float_error_bound, SolveReport};
+
+        let coefficients = vec![-4.0, 0.0, 1.0];
+        assert_eq!(root_float_error_bound(&coefficients, 2.0), 0.0);
+
+        let perturbed = 2.0 + 1e-9;
+        let bound = root_float_error_bound(&coefficients, perturbed);
+        assert!(bound < 1e-8);
+        assert_abs_diff_eq!(bound, 1e-9, epsilon = 1e-15);
+
+        let report = SolveReport::for_root(&coefficients, perturbed);
+        assert_eq!(report.accuracy_bound, bound);
+    }
+
+    // Test adaptive-precision Newton reaches a 1e-50 residual on x² - 2 by raising the precision
+    #[test]
+    fn test_newton_root_adaptive_precision() {
+        use num::{BigInt, BigRational, Signed};
+
+        let integer = |n: i64| BigRational::from_integer(BigInt::from(n));
+        let coefficients = vec![integer(-2), integer(0), integer(1)];
+        let tolerance = BigRational::new(BigInt::from(1), num::pow(BigInt::from(10), 50));
+        let solver = HyperCatalanPolynomialSolver::new(2, 10);
+
+        let (root, bits) = solver.newton_root_adaptive_precision(
+            &coefficients,
+            BigRational::new(BigInt::from(3), BigInt::from(2)),
+            &tolerance,
+            1024,
+        );
+        assert!((&root * &root - integer(2)).abs() <= tolerance);
+        // 1e-50 needs about 167 bits, reached by doubling 53 -> 106 -> 212
+        assert_eq!(bits, 212);
+        assert!(root.denom().bits() <= 212);
+    }
+
+    // Test the stars-and-bars count matches the enumeration
+    #[test]
+    fn test_count_subdigon_types() {
+        use crate::subdigon::{count_subdigon_types, generate_types, SubdigonTypes};
+
+        for total_faces in 0..7 {
+            for max_polygon_size in 0..5 {
+                let count = count_subdigon_types(total_faces, max_polygon_size);
+                assert_eq!(count, generate_types(total_faces, max_polygon_size).len());
+                assert_eq!(count, SubdigonTypes::new(total_faces, max_polygon_size).count());
+            }
+        }
+        assert_eq!(count_subdigon_types(30, 10), 211915132);
+    }
+
+    // Test the normalized residual is unchanged by scaling the polynomial, unlike the raw one
+    #[test]
+    fn test_normalized_residual() {
+        use crate::report::SolveReport;
+
+        // √3 to eight decimals
+        let root = 1.732_050_81;
+        let well_scaled = vec![-3.0, 0.0, 1.0];
+        let ill_scaled: Vec<f64> = well_scaled.iter().map(|c| c * 1e10).collect();
+
+        let well = SolveReport::for_root(&well_scaled, root);
+        let ill = SolveReport::for_root(&ill_scaled, root);
+        // Equal up to the rounding of the cancelling terms
+        assert!((ill.residual / well.residual / 1e10 - 1.0).abs() < 1e-6);
+        assert!((ill.normalized_residual / well.normalized_residual - 1.0).abs() < 1e-6);
+        assert!(well.normalized_residual > 0.0 && well.normalized_residual < 1e-8);
+    }
+
+    // Test parsing polynomials from strings, including Display's output
+    #[test]
+    fn test_polynomial_from_str() {
+        let p: Polynomial = "3x^2 - 2.5*x + 1".parse().unwrap();
+        assert_eq!(p.coefficients, vec![1.0, -2.5, 3.0]);
+
+        let cubic = Polynomial::new(vec![-6.0, 11.0, -6.0, 1.0]);
+        assert_eq!(cubic.to_string().parse::<Polynomial>().unwrap(), cubic);
+
+        // Repeated powers add up, and scientific notation is not split at its sign
+        let p: Polynomial = "-x + 1e-3 + 2x".parse().unwrap();
+        assert_eq!(p.coefficients, vec![1e-3, 1.0]);
+
+        assert_eq!("x^2 + y".parse::<Polynomial>(), Err("Invalid term '+y'".to_string()));
+        assert!("".parse::<Polynomial>().is_err());
+        assert!("x^".parse::<Polynomial>().is_err());
+    }
+
+    // Test indexing and iterating over a polynomial's terms, constant term first
+    #[test]
+    fn test_polynomial_index_and_iter() {
+        let p = Polynomial::new(vec![-4.0, 0.0, 1.0]);
+        assert_eq!(p[0], -4.0);
+        assert_eq!(p[1], 0.0);
+        assert_eq!(p[2], 1.0);
+        assert_eq!(p[7], 0.0);
+
+        let terms: Vec<(usize, f64)> = p.iter().collect();
+        assert_eq!(terms, vec![(0, -4.0), (1, 0.0), (2, 1.0)]);
+
+        let nonzero: Vec<usize> = (&p).into_iter().filter(|&(_, c)| c != 0.0).map(|(power, _)| power).collect();
+        assert_eq!(nonzero, vec![0, 2]);
+    }
+
+    // Test classifying the roots of (x - 1)²(x² + 1)
+    #[test]
+    fn test_classify_roots() {
+        // (x² - 2x + 1)(x² + 1) = x⁴ - 2x³ + 2x² - 2x + 1
+        let coefficients = vec![1.0, -2.0, 2.0, -2.0, 1.0];
+        let mut solver = HyperCatalanPolynomialSolver::new(4, 10);
+        let summary = solver.classify_roots(&coefficients);
+
+        assert_eq!(summary.real.len(), 1);
+        assert_abs_diff_eq!(summary.real[0], 1.0, epsilon = 1e-6);
+        assert_eq!(summary.multiplicities, vec![2]);
+
+        assert_eq!(summary.complex.len(), 2);
+        assert_abs_diff_eq!(summary.complex[0].re, 0.0, epsilon = 1e-10);
+        assert_abs_diff_eq!(summary.complex[0].im.abs(), 1.0, epsilon = 1e-10);
+        assert_abs_diff_eq!(summary.complex[1].im, -summary.complex[0].im, epsilon = 1e-10);
+
+        // Complex roots come in the order of sort_roots: ±i before ±2i, whatever their real order
+        // (x² + 1)(x² + 4)(x - 3) = x⁵ - 3x⁴ + 5x³ - 15x² + 4x - 12
+        let summary = solver.classify_roots(&[-12.0, 4.0, -15.0, 5.0, -3.0, 1.0]);
+        assert_eq!(summary.real.len(), 1);
+        let magnitudes: Vec<f64> = summary.complex.iter().map(|c| c.norm()).collect();
+        for (magnitude, expected) in magnitudes.iter().zip([1.0, 1.0, 2.0, 2.0]) {
+            assert_abs_diff_eq!(*magnitude, expected, epsilon = 1e-10);
+        }
+    }
+
+    // Test scaled evaluation stays finite and correctly signed where powi overflows
+    #[test]
+    fn test_evaluate_polynomial_scaled() {
+        use crate::evaluate_polynomial_scaled;
+
+        // 1e-200·x²⁰ - x at x = 1e20 is about 1e200, but x²⁰ alone overflows
+        let mut coefficients = vec![0.0; 21];
+        coefficients[1] = -1.0;
+        coefficients[20] = 1e-200;
+        assert!(!evaluate_polynomial(&coefficients, 1e20).is_finite());
+        let value = evaluate_polynomial_scaled(&coefficients, 1e20);
+        assert!((value / 1e200 - 1.0).abs() < 1e-10);
+
+        // -x²¹ at x = 1e20 is beyond f64 and saturates with its sign
+        let mut odd = vec![0.0; 22];
+        odd[21] = -1.0;
+        assert_eq!(evaluate_polynomial_scaled(&odd, 1e20), -f64::MAX);
+        assert_eq!(evaluate_polynomial_scaled(&odd, -1e20), f64::MAX);
+
+        // Ordinary arguments match plain evaluation
+        let cubic = vec![-6.0, 11.0, -6.0, 1.0];
+        for &x in &[-3.0, 0.5, 2.5, 40.0] {
+            assert_abs_diff_eq!(evaluate_polynomial_scaled(&cubic, x), evaluate_polynomial(&cubic, x), epsilon = 1e-9);
+        }
+    }
+
+    // Test best-effort mode returns a finite estimate flagged as not converged
+    #[test]
+    fn test_best_effort() {
+        use crate::SolverError;
+
+        // t₂ = 0.24 is just inside the radius 1/4, so ten face levels fall short
+        let coefficients = vec![0.6, -0.5, 0.1];
+        let mut solver = HyperCatalanPolynomialSolver::new(2, 10);
+        assert!(matches!(
+            solver.solve_polynomial(&coefficients),
+            Err(SolverError::NotConverged { .. })
+        ));
+
+        solver.set_best_effort(true);
+        let report = solver.solve_polynomial_report(&coefficients).unwrap();
+        assert!(report.root.is_finite());
+        assert!(!report.converged);
+        assert!((report.root - 2.0).abs() < 0.5);
+
+        // A converging polynomial is still reported as converged
+        assert!(solver.solve_polynomial_report(&[0.1, -0.4, 0.1]).unwrap().converged);
+    }
+
+    // Test random restarts are reproducible per seed and accept any random source
+    #[test]
+    fn test_random_source_seam() {
+        use crate::rng::{RandomSource, SplitMix64};
+
+        // Walks [0, 1) in steps of 1/8, mapped onto [low, high)
+        struct Stepper(u64);
+        impl RandomSource for Stepper {
+            fn next_u64(&mut self) -> u64 {
+                self.0 = (self.0 + 1) % 8;
+                self.0 << 61
+            }
+        }
+
+        let coefficients = vec![-6.0, 11.0, -6.0, 1.0];
+        let solver = HyperCatalanPolynomialSolver::new(3, 10);
+
+        let first = solver.find_roots_random_restart(&coefficients, 30, 2024);
+        let second = solver.find_roots_random_restart(&coefficients, 30, 2024);
+        assert_eq!(first, second);
+        assert_eq!(
+            solver.find_roots_random_restart_with_rng(&coefficients, 30, &mut SplitMix64::new(2024)),
+            first
+        );
+
+        // Guesses -12, -9, ..., 9 across the Cauchy bound reach the outer roots
+        let stepped = solver.find_roots_random_restart_with_rng(&coefficients, 16, &mut Stepper(0));
+        assert_eq!(stepped.len(), 2);
+        assert_abs_diff_eq!(stepped[0], 1.0, epsilon = 1e-10);
+        assert_abs_diff_eq!(stepped[1], 3.0, epsilon = 1e-10);
+    }
+
+    // Test solving even and odd polynomials at half the degree
+    #[test]
+    fn test_parity_roots() {
+        use num::Complex;
+        use crate::polynomial::{is_even, is_odd, parity_roots, reduce_parity};
+
+        // x⁴ - 5x² + 4 = (x² - 1)(x² - 4)
+        let even = vec![4.0, 0.0, -5.0, 0.0, 1.0];
+        assert!(is_even(&even) && !is_odd(&even));
+        assert_eq!(reduce_parity(&even), Some(vec![4.0, -5.0, 1.0]));
+        // Sorted by magnitude, then real part
+        let roots = parity_roots(&even).unwrap();
+        assert_eq!(roots.len(), 4);
+        for (root, expected) in roots.iter().zip([-1.0, 1.0, -2.0, 2.0]) {
+            assert_abs_diff_eq!(root.re, expected, epsilon = 1e-12);
+            assert_abs_diff_eq!(root.im, 0.0, epsilon = 1e-12);
+        }
+
+        // x³ + x = x(x² + 1) has roots 0 and ±i
+        let odd = vec![0.0, 1.0, 0.0, 1.0];
+        assert!(is_odd(&odd) && !is_even(&odd));
+        let roots = parity_roots(&odd).unwrap();
+        assert_eq!(roots.len(), 3);
+        assert_eq!(roots[0], Complex::new(0.0, 0.0));
+        assert_abs_diff_eq!(roots[1].re, 0.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(roots[1].im, -1.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(roots[2].im, 1.0, epsilon = 1e-12);
+
+        // x³ - x: the root 0 and then ±1, negative first
+        let roots = parity_roots(&[0.0, -1.0, 0.0, 1.0]).unwrap();
+        assert_eq!(roots, vec![Complex::new(0.0, 0.0), Complex::new(-1.0, 0.0), Complex::new(1.0, 0.0)]);
+
+        assert_eq!(parity_roots(&[-6.0, 11.0, -6.0, 1.0]), None);
+    }
+
+    // Test the cache iterator yields every computed type with its value
+    #[test]
+    fn test_cached_entries() {
+        use crate::calculator::hyper_catalan_number;
+
+        let mut calculator = HyperCatalanCalculator::new();
+        let types = [
+            SubdigonType::new(vec![1, 0]),
+            SubdigonType::new(vec![2, 0]),
+            SubdigonType::new(vec![2, 1]),
+        ];
+        for type_ in &types {
+            calculator.calculate(type_);
+        }
+
+        let mut entries: Vec<_> = calculator.cached_entries().collect();
+        entries.sort_by(|a, b| a.0.cmp(b.0));
+        assert_eq!(entries.len(), types.len());
+        for ((type_, value), expected) in entries.into_iter().zip(&types) {
+            assert_eq!(type_, expected);
+            assert_eq!(*value, hyper_catalan_number(expected));
+        }
+    }
+
+    // Test the f32 entry point solves a quadratic to f32 precision
+    #[test]
+    fn test_solve_polynomial_f32() {
+        // x² - 4x + 1 scaled by 0.25; the root 2 - √3 is well conditioned
+        let coefficients = [0.25f32, -1.0, 0.25];
+        let mut solver = HyperCatalanPolynomialSolver::new(2, 20);
+        let root = solver.solve_polynomial_f32(&coefficients).unwrap();
+
+        let expected = (2.0 - 3.0f64.sqrt()) as f32;
+        assert!((root - expected).abs() <= 2.0 * f32::EPSILON * expected);
+    }
+
+    // Test the report names the subdigon type with the largest series term
+    #[test]
+    fn test_dominant_type() {
+        let mut solver = HyperCatalanPolynomialSolver::new(3, 10);
+
+        // t₂ = 1.5e-4 is tiny, so the empty subdigon's term 1 dominates
+        let report = solver.solve_polynomial_report(&[1.0, -100.0, 1.5]).unwrap();
+        assert_eq!(report.dominant_type, Some(SubdigonType::new(vec![0, 0])));
+
+        // Exact rational roots skip the series entirely
+        solver.set_rational_shortcut(true);
+        let report = solver.solve_polynomial_report(&[-6.0, 11.0, -6.0, 1.0]).unwrap();
+        assert_eq!(report.dominant_type, None);
+    }
+
+    // Test deflation order on widely separated roots: the stable pairing keeps every root accurate
+    #[test]
+    fn test_deflation_order() {
+        use crate::polynomial::{deflate_backward, deflate_forward};
+        use crate::solver::DeflationOrder;
+
+        // (x - 0.001)(x - 1)(x - 1000)
+        let coefficients = vec![-1.0, 1001.001, -1001.001, 1.0];
+        let exact = [0.001, 1.0, 1000.0];
+        let estimate = |r: f64| r * (1.0 + 1e-13);
+        let last_root = |q: Vec<f64>| -q[0] / q[1];
+
+        // Dividing out the two largest estimates forward ruins the smallest root,
+        // while smallest-first forward or largest-first backward keep it
+        let wrong = last_root(deflate_forward(&deflate_forward(&coefficients, estimate(1000.0)), estimate(1.0)));
+        let forward = last_root(deflate_forward(&deflate_forward(&coefficients, estimate(0.001)), estimate(1.0)));
+        let backward = last_root(deflate_backward(&deflate_backward(&coefficients, estimate(1000.0)), estimate(1.0)));
+        assert!((wrong - 0.001).abs() / 0.001 > 1e-8);
+        assert!((forward - 1000.0).abs() / 1000.0 < 1e-12);
+        assert!((backward - 0.001).abs() / 0.001 < 1e-12);
+
+        let mut solver = HyperCatalanPolynomialSolver::new(3, 20);
+        for order in [DeflationOrder::SmallestFirst, DeflationOrder::LargestFirst] {
+            solver.set_deflation_order(order);
+            let roots = solver.solve_all_roots(&coefficients);
+            assert_eq!(roots.len(), 3);
+            for (root, expected) in roots.iter().zip(exact) {
+                assert!((root - expected).abs() / expected < 1e-10);
+            }
+        }
+
+        // Constants and empty slices have nothing to divide out
+        for coefficients in [&[][..], &[5.0][..]] {
+            assert!(deflate_forward(coefficients, 2.0).is_empty());
+            assert!(deflate_backward(coefficients, 2.0).is_empty());
+        }
+    }
+
+    // Test every method selected with set_method solves x² - 4
+    #[test]
+    fn test_set_method() {
+        use crate::solver::Method;
+
+        let coefficients = vec![-4.0, 0.0, 1.0];
+        let mut solver = HyperCatalanPolynomialSolver::new(2, 20);
+        let methods = [
+            Method::SeriesNewton,
+            Method::DurandKerner,
+            Method::Laguerre,
+            #[cfg(feature = "nalgebra")]
+            Method::CompanionMatrix,
+        ];
+
+        for method in methods {
+            solver.set_method(method);
+            let mut roots = solver.solve_all(&coefficients);
+            roots.sort_by(|a, b| a.re.total_cmp(&b.re));
+            assert_eq!(roots.len(), 2, "{:?}", method);
+            for (root, expected) in roots.iter().zip([-2.0, 2.0]) {
+                assert_abs_diff_eq!(root.re, expected, epsilon = 1e-10);
+                assert_abs_diff_eq!(root.im, 0.0, epsilon = 1e-10);
+            }
+        }
+    }
+
+    // Test the Newton iteration estimate is a handful of steps and matches real iterations
+    #[test]
+    fn test_estimate_newton_iterations() {
+        use crate::solver::estimate_newton_iterations;
+
+        let estimate = estimate_newton_iterations(1e-1, 1e-15);
+        assert_eq!(estimate, 6);
+        assert_eq!(estimate_newton_iterations(1e-15, 1e-15), 0);
+        assert_eq!(estimate_newton_iterations(2e-15, 1e-15), 1);
+
+        // Newton on x² - 2 from a residual near 1e-1 gets there within the estimate
+        let coefficients = vec![-2.0, 0.0, 1.0];
+        let solver = HyperCatalanPolynomialSolver::new(2, 10);
+        let guess = (2.1f64).sqrt();
+        let initial = evaluate_polynomial(&coefficients, guess).abs();
+        let root = solver.newton_root(&coefficients, guess, estimate_newton_iterations(initial, 1e-15));
+        assert!(evaluate_polynomial(&coefficients, root).abs() <= 1e-15);
+    }
+
+    // Test the complex series path reaches both roots ±i of x² + 1
+    #[test]
+    fn test_solve_polynomial_complex() {
+        use crate::solver::{to_geometric_form, to_geometric_form_complex};
+        use crate::SolverError;
+        use num::Complex;
+
+        let coefficients = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)];
+        let mut solver = HyperCatalanPolynomialSolver::new(2, 20);
+        assert_eq!(solver.solve_polynomial_complex(&coefficients), Err(SolverError::ZeroLinearCoefficient));
+
+        for expected in [Complex::new(0.0, 1.0), Complex::new(0.0, -1.0)] {
+            let root = solver.solve_polynomial_complex_near(&coefficients, expected * 0.9).unwrap();
+            assert_abs_diff_eq!(root.re, expected.re, epsilon = 1e-10);
+            assert_abs_diff_eq!(root.im, expected.im, epsilon = 1e-10);
+        }
+
+        // Real coefficients give the same geometric form as the real conversion
+        let real = to_geometric_form(&[6.0, 5.0, 1.0]).unwrap();
+        let complex = to_geometric_form_complex(&[Complex::new(6.0, 0.0), Complex::new(5.0, 0.0), Complex::new(1.0, 0.0)]).unwrap();
+        for (r, c) in real.iter().zip(&complex) {
+            assert_abs_diff_eq!(*r, c.re, epsilon = 1e-15);
+            assert_abs_diff_eq!(c.im, 0.0);
+        }
+    }
+
+    // Test the fixed-degree evaluation agrees with the slice version
+    #[test]
+    fn test_evaluate_fixed() {
+        use crate::evaluate_fixed;
+
+        // Small integer coefficients at dyadic points are exact in both evaluations
+        let cubic = [-6.0, 11.0, -6.0, 1.0];
+        for i in -16..=16 {
+            let x = i as f64 / 4.0;
+            assert_eq!(evaluate_fixed(&cubic, x), evaluate_polynomial(&cubic, x));
+        }
+        for x in [1.0, 2.0, 3.0] {
+            assert_eq!(evaluate_fixed(&cubic, x), 0.0);
+        }
+
+        // Otherwise the two agree to rounding, Horner's order of operations aside
+        let quadratic = [0.3, -1.7, 2.9];
+        for x in [-1.3, 0.1, 0.77, 4.2] {
+            assert_abs_diff_eq!(evaluate_fixed(&quadratic, x), evaluate_polynomial(&quadratic, x), epsilon = 1e-13);
+        }
+        assert_eq!(evaluate_fixed(&[], 2.0), 0.0);
+    }
+
+    // Test the nearest root to a guess is picked among all roots
+    #[test]
+    fn test_nearest_root() {
+        use num::Complex;
+
+        // (x - 1)(x - 2)(x - 3)
+        let coefficients = vec![-6.0, 11.0, -6.0, 1.0];
+        let mut solver = HyperCatalanPolynomialSolver::new(3, 20);
+        let root = solver.nearest_root(&coefficients, Complex::new(1.9, 0.0)).unwrap();
+        assert_abs_diff_eq!(root.re, 2.0, epsilon = 1e-8);
+        assert_abs_diff_eq!(root.im, 0.0, epsilon = 1e-8);
+
+        // x² + 1 has only complex roots; the guess picks -i
+        let root = solver.nearest_root(&[1.0, 0.0, 1.0], Complex::new(0.5, -0.5)).unwrap();
+        assert_abs_diff_eq!(root.re, 0.0, epsilon = 1e-8);
+        assert_abs_diff_eq!(root.im, -1.0, epsilon = 1e-8);
+
+        assert_eq!(solver.nearest_root(&[5.0], Complex::new(0.0, 0.0)), None);
+    }
+
+    // Test a polynomial roundtrips through GeometricForm
+    #[test]
+    fn test_geometric_form_struct() {
+        use crate::solver::{to_geometric_form, GeometricForm};
+
+        // 1 + 5x - 0.2x² + 0.1x³ has t₂ = c₂c₀/c₁² = -0.008 and t₃ = -c₃c₀²/c₁³ = -0.0008
+        let polynomial = Polynomial::new(vec![1.0, 5.0, -0.2, 0.1]);
+        let form = GeometricForm::from_polynomial(&polynomial).unwrap();
+        assert_eq!(form.t(0), 1.0);
+        assert_eq!(form.t(1), -1.0);
+        assert_abs_diff_eq!(form.t(2), -0.008, epsilon = 1e-15);
+        assert_abs_diff_eq!(form.t(3), -0.0008, epsilon = 1e-15);
+        assert_eq!(form.t(4), 0.0);
+        assert_eq!(form.polygon_t(1), form.t(3));
+
+        let geometric = form.to_polynomial();
+        assert_eq!(geometric.coefficients, to_geometric_form(&polynomial.coefficients).unwrap());
+        // A geometric form is its own geometric form (c₀ = 1, c₁ = -1)
+        assert_eq!(GeometricForm::from_polynomial(&geometric).unwrap(), form);
+    }
+
+    // Test the power series of the root starts with the Catalan and Fuss-Catalan numbers
+    #[test]
+    fn test_root_power_series() {
+        use crate::subdigon::count_subdigon_types;
+        use num::{BigInt, BigRational};
+
+        let mut solver = HyperCatalanPolynomialSolver::new(3, 10);
+        let series = solver.root_power_series(4);
+
+        // Digons alone, m = (k, 0), give the Catalan numbers 1, 1, 2, 5, 14
+        let catalan: Vec<BigRational> = series.iter()
+            .filter(|(type_, _)| type_.m[1] == 0)
+            .map(|(_, c)| c.clone())
+            .collect();
+        let expected: Vec<BigRational> = [1, 1, 2, 5, 14].iter()
+            .map(|&c| BigRational::from_integer(BigInt::from(c)))
+            .collect();
+        assert_eq!(catalan, expected);
+
+        // Trigons alone, m = (0, k), give the Fuss-Catalan numbers 1, 1, 3, 12, 55
+        let trigons: Vec<BigRational> = series.iter()
+            .filter(|(type_, _)| type_.m[0] == 0)
+            .map(|(_, c)| c.clone())
+            .collect();
+        let expected: Vec<BigRational> = [1, 1, 3, 12, 55].iter()
+            .map(|&c| BigRational::from_integer(BigInt::from(c)))
+            .collect();
+        assert_eq!(trigons, expected);
+
+        // One entry per subdigon type, starting with the empty type's 1
+        assert_eq!(series.len(), (0..=4).map(|faces| count_subdigon_types(faces, 2)).sum::<usize>());
+        assert_eq!(series[0].0, SubdigonType::new(vec![0, 0]));
+    }
+
+    // Test counts past i32::MAX do not overflow, and large numbers stay exact
+    #[test]
+    fn test_subdigon_counts_beyond_i32() {
+        use crate::calculator::hyper_catalan_number;
+        use num::{BigInt, BigRational};
+
+        // i32::MAX - 1 trigons have 3·(2³¹ - 2) / 2 edges
+        let type_ = SubdigonType::new(vec![0, i32::MAX - 1]);
+        assert_eq!(type_.faces(), 2_147_483_646);
+        assert_eq!(type_.edges(), 3_221_225_469);
+        assert!(type_.edges() > i32::MAX as i64);
+        assert_eq!(type_.vertices(), 1_073_741_825);
+        assert_eq!(type_.euler_characteristic(), 2);
+        assert_eq!(type_.topology().unwrap().edges, 3_221_225_469);
+
+        // The 1000th Catalan number, C(2000, 1000) / 1001, from the digon type (1000)
+        let catalan = num::integer::binomial(BigInt::from(2000), BigInt::from(1000)) / BigInt::from(1001);
+        assert_eq!(hyper_catalan_number(&SubdigonType::new(vec![1000])), BigRational::from_integer(catalan));
+    }
+
+    // Test multi-root results come back in a fixed order by magnitude
+    #[test]
+    fn test_sort_roots() {
+        use crate::roots::sort_roots;
+        use crate::solver::Method;
+        use num::Complex;
+
+        let mut roots = vec![
+            Complex::new(-2.0, 0.0),
+            Complex::new(0.0, 1.0),
+            Complex::new(2.0, 0.0),
+            Complex::new(0.0, -1.0),
+            Complex::new(0.5, 0.0),
+        ];
+        sort_roots(&mut roots);
+        assert_eq!(roots, vec![
+            Complex::new(0.5, 0.0),
+            Complex::new(0.0, -1.0),
+            Complex::new(0.0, 1.0),
+            Complex::new(-2.0, 0.0),
+            Complex::new(2.0, 0.0),
+        ]);
+
+        // (x - 1)(x + 3)(x² + 4): repeated solves agree entry by entry
+        let coefficients = vec![-12.0, 8.0, 1.0, 2.0, 1.0];
+        for method in [Method::DurandKerner, Method::Laguerre] {
+            let mut solver = HyperCatalanPolynomialSolver::new(4, 10);
+            solver.set_method(method);
+            let first = solver.solve_all(&coefficients);
+            assert_eq!(first, solver.solve_all(&coefficients), "{:?}", method);
+            assert!(first.windows(2).all(|pair| pair[0].norm() <= pair[1].norm()), "{:?}", method);
+            assert_abs_diff_eq!(first[0].re, 1.0, epsilon = 1e-10);
+        }
+        let mut solver = HyperCatalanPolynomialSolver::new(4, 10);
+        assert_eq!(solver.solve_robust(&coefficients), solver.solve_robust(&coefficients));
+    }
+
+    // Test the convergence order separates simple and multiple roots
+    #[test]
+    fn test_estimate_convergence_order() {
+        use crate::solver::estimate_convergence_order;
+
+        let solver = HyperCatalanPolynomialSolver::new(3, 10);
+
+        // x² - 2 has a simple root at √2: quadratic convergence
+        let history = solver.bootstrap_root_history(&[-2.0, 0.0, 1.0], 3.0, 100, 1e-15);
+        assert_abs_diff_eq!(estimate_convergence_order(&history), 2.0, epsilon = 0.1);
+
+        // (x - 1)³ has a triple root: each step only removes a third of the error
+        let history = solver.bootstrap_root_history(&[-1.0, 3.0, -3.0, 1.0], 2.0, 100, 1e-15);
+        assert_abs_diff_eq!(estimate_convergence_order(&history), 1.0, epsilon = 0.1);
+
+        assert!(estimate_convergence_order(&history[..2]).is_nan());
+    }
+}
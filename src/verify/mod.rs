@@ -0,0 +1,106 @@
+use std::ops::{Add, Mul, Sub};
+
+use crate::solver::HighPrecFloat;
+
+/// Closed interval `[lo, hi]` with outward-widened arithmetic
+///
+/// Every operation widens its result by one ulp on each side, which encloses the
+/// exact result given correctly rounded floating-point operations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Interval {
+    lo: HighPrecFloat,
+    hi: HighPrecFloat,
+}
+
+impl Interval {
+    fn new(lo: HighPrecFloat, hi: HighPrecFloat) -> Self {
+        Interval { lo, hi }
+    }
+
+    fn point(x: HighPrecFloat) -> Self {
+        Interval::new(x, x)
+    }
+
+    fn widened(lo: HighPrecFloat, hi: HighPrecFloat) -> Self {
+        Interval::new(lo.next_down(), hi.next_up())
+    }
+
+    /// Whether `self` lies strictly inside `other`
+    fn strictly_inside(&self, other: &Interval) -> bool {
+        other.lo < self.lo && self.hi < other.hi
+    }
+}
+
+impl Add for Interval {
+    type Output = Interval;
+
+    fn add(self, other: Interval) -> Interval {
+        Interval::widened(self.lo + other.lo, self.hi + other.hi)
+    }
+}
+
+impl Sub for Interval {
+    type Output = Interval;
+
+    fn sub(self, other: Interval) -> Interval {
+        Interval::widened(self.lo - other.hi, self.hi - other.lo)
+    }
+}
+
+impl Mul for Interval {
+    type Output = Interval;
+
+    fn mul(self, other: Interval) -> Interval {
+        let products = [
+            self.lo * other.lo,
+            self.lo * other.hi,
+            self.hi * other.lo,
+            self.hi * other.hi,
+        ];
+        let lo = products.iter().cloned().fold(HighPrecFloat::INFINITY, HighPrecFloat::min);
+        let hi = products.iter().cloned().fold(HighPrecFloat::NEG_INFINITY, HighPrecFloat::max);
+        Interval::widened(lo, hi)
+    }
+}
+
+/// Enclose the range of a polynomial and its derivative over an interval (Horner)
+fn eval_interval(coefficients: &[HighPrecFloat], x: Interval) -> (Interval, Interval) {
+    let mut value = Interval::point(0.0);
+    let mut derivative = Interval::point(0.0);
+    for &coeff in coefficients.iter().rev() {
+        derivative = derivative * x + value;
+        value = value * x + Interval::point(coeff);
+    }
+    (value, derivative)
+}
+
+/// Prove that a polynomial has exactly one root in `[a, b]` using the Krawczyk operator
+///
+/// With midpoint `m` and `Y ≈ 1/p'(m)`, the Krawczyk operator is
+/// `K(X) = m - Y·p(m) + (1 - Y·p'(X))·(X - m)`. If `K(X)` lies strictly inside `X`,
+/// the interval contains a unique root. Unlike the interval Newton operator this never
+/// divides by the interval `p'(X)`, so it remains usable when that enclosure is wide.
+/// Returning `false` means the test was inconclusive, not that no root exists.
+pub fn verify_root_krawczyk(coefficients: &[HighPrecFloat], candidate_interval: (HighPrecFloat, HighPrecFloat)) -> bool {
+    let (a, b) = candidate_interval;
+    if !a.is_finite() || !b.is_finite() || a >= b || coefficients.len() < 2 {
+        return false;
+    }
+
+    let x = Interval::new(a, b);
+    let m = a + (b - a) / 2.0;
+
+    let (value_at_m, derivative_at_m) = eval_interval(coefficients, Interval::point(m));
+    let slope = (derivative_at_m.lo + derivative_at_m.hi) / 2.0;
+    if slope == 0.0 || !slope.is_finite() {
+        return false;
+    }
+    let y = Interval::point(1.0 / slope);
+
+    let (_, derivative_range) = eval_interval(coefficients, x);
+    let m_interval = Interval::point(m);
+    let krawczyk = m_interval - y * value_at_m
+        + (Interval::point(1.0) - y * derivative_range) * (x - m_interval);
+
+    krawczyk.strictly_inside(&x)
+}